@@ -0,0 +1,158 @@
+//! The registry of accepted answers in `answers.toml`, used to catch refactors that change
+//! results.
+//!
+//! Only the part of TOML the registry needs is understood: comments, `[[answer]]` tables and
+//! `key = value` pairs where the value is a string or an integer.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs::read_to_string;
+use std::io::Error as IoError;
+use std::path::PathBuf;
+
+use crate::{input, solver, Part};
+
+/// Where the registry is kept in the repository.
+pub const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+
+/// An answer the puzzle accepted for an input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+
+    /// Relative to the data directory unless it's absolute.
+    pub input: PathBuf,
+
+    pub answer: String,
+}
+
+impl Answer {
+    pub fn input_path(&self) -> PathBuf {
+        input::data_dir().join(&self.input)
+    }
+
+    /// Solve the puzzle again, returning the answer found now.
+    pub fn solve(&self) -> Result<String, Box<dyn Error>> {
+        let solve = solver(self.day)
+            .ok_or_else(|| IoError::other(format!("day {} is not solved", self.day)))?;
+        let path = self.input_path();
+        let contents = input::read(self.day, path.to_str())?;
+        solve(&contents, self.part)
+    }
+}
+
+pub fn load(path: &str) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let text = read_to_string(path).map_err(|error| IoError::other(format!("{path}: {error}")))?;
+    parse(&text)
+}
+
+pub fn parse(text: &str) -> Result<Vec<Answer>, Box<dyn Error>> {
+    // The line each table starts on and its fields.
+    let mut tables: Vec<(usize, BTreeMap<&str, String>)> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let error =
+            |message: String| IoError::other(format!("answers line {line_number}: {message}"));
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line == "[[answer]]" {
+            tables.push((line_number, BTreeMap::new()));
+            continue;
+        }
+
+        let (_, fields) = tables
+            .last_mut()
+            .ok_or_else(|| error("expected '[[answer]]'".to_owned()))?;
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected 'key = value', found '{line}'")))?;
+        let (key, value) = (key.trim(), value.trim());
+        let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(string) => string.to_owned(),
+            None if value.parse::<i64>().is_ok() => value.to_owned(),
+            None => {
+                return Err(error(format!("expected a string or integer, found '{value}'")).into())
+            }
+        };
+        if fields.insert(key, value).is_some() {
+            return Err(error(format!("'{key}' is repeated")).into());
+        }
+    }
+
+    tables
+        .into_iter()
+        .map(|(line_number, mut fields)| {
+            let error =
+                |message: String| IoError::other(format!("answers line {line_number}: {message}"));
+            let mut field = |key: &str| {
+                fields
+                    .remove(key)
+                    .ok_or_else(|| error(format!("missing '{key}'")))
+            };
+            let answer = Answer {
+                day: field("day")?.parse()?,
+                part: field("part")?.parse()?,
+                input: PathBuf::from(field("input")?),
+                answer: field("answer")?,
+            };
+            if let Some(key) = fields.keys().next() {
+                return Err(error(format!("unknown key '{key}'")).into());
+            }
+            Ok(answer)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_registry() {
+        let answers = parse(
+            "# Comment
+            [[answer]]
+            day = 6
+            part = \"b\"
+            input = \"other/day06.txt\"
+            answer = \"71503\"",
+        )
+        .unwrap();
+        assert_eq!(
+            answers,
+            vec![Answer {
+                day: 6,
+                part: Part::B,
+                input: PathBuf::from("other/day06.txt"),
+                answer: "71503".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_missing_key() {
+        let error = parse("[[answer]]\nday = 6\npart = \"b\"\nanswer = \"1\"").unwrap_err();
+        assert_eq!(error.to_string(), "answers line 1: missing 'input'");
+    }
+
+    #[test]
+    fn registered_answers() {
+        let answers = load(ANSWERS_PATH).unwrap();
+        let mismatches: Vec<String> = answers
+            .iter()
+            .filter_map(|answer| match answer.solve() {
+                Ok(actual) if actual == answer.answer => None,
+                Ok(actual) => Some(format!(
+                    "day {} part {}: expected {}, found {actual}",
+                    answer.day, answer.part, answer.answer
+                )),
+                Err(error) => Some(format!("day {} part {}: {error}", answer.day, answer.part)),
+            })
+            .collect();
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+}
@@ -0,0 +1,144 @@
+//! Timing how long each day takes to parse its input and to solve each part.
+//!
+//! Run in release mode, such as `cargo run --release --bin aoc -- bench --all`, for numbers
+//! that mean anything.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{Part, Solution};
+
+/// The spread of the samples taken for one phase.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+
+    /// The middle sample, or the later of the two middle samples for an even count.
+    pub median: Duration,
+
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: *samples.first().expect("at least one sample"),
+            median: samples[samples.len() / 2],
+            max: *samples.last().unwrap(),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"min\": {}, \"median\": {}, \"max\": {}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: Part,
+    pub iterations: usize,
+    pub parse: Timings,
+    pub solve: Timings,
+}
+
+impl Benchmark {
+    /// Durations are in nanoseconds.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\": {}, \"part\": \"{}\", \"iterations\": {}, \"parse_ns\": {}, \"solve_ns\": {}}}",
+            self.day,
+            self.part,
+            self.iterations,
+            self.parse.to_json(),
+            self.solve.to_json()
+        )
+    }
+}
+
+/// A JSON array of the benchmarks, one per line.
+pub fn to_json(benchmarks: &[Benchmark]) -> String {
+    let mut json = String::from("[");
+    for (index, benchmark) in benchmarks.iter().enumerate() {
+        let separator = if index == 0 { "" } else { "," };
+        write!(json, "{separator}\n  {}", benchmark.to_json()).unwrap();
+    }
+    json.push_str("\n]");
+    json
+}
+
+/// Parse and solve one part `iterations` times, timing each separately.
+pub fn bench<S: Solution>(
+    input: &str,
+    part: Part,
+    iterations: usize,
+) -> Result<Benchmark, Box<dyn Error>> {
+    let iterations = iterations.max(1);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solve_samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(black_box(input))?);
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(match part {
+            Part::A => S::part_one(&parsed)?,
+            Part::B => S::part_two(&parsed)?,
+        });
+        solve_samples.push(start.elapsed());
+    }
+
+    Ok(Benchmark {
+        day: S::DAY,
+        part,
+        iterations,
+        parse: Timings::from_samples(parse_samples),
+        solve: Timings::from_samples(solve_samples),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day09::Day09;
+
+    #[test]
+    fn timings_from_samples() {
+        let samples = [3, 1, 4, 1, 5].map(Duration::from_nanos).to_vec();
+        let timings = Timings::from_samples(samples);
+        assert_eq!(timings.min, Duration::from_nanos(1));
+        assert_eq!(timings.median, Duration::from_nanos(3));
+        assert_eq!(timings.max, Duration::from_nanos(5));
+    }
+
+    #[test]
+    fn bench_json() {
+        let benchmark = bench::<Day09>("0 3 6 9 12 15", Part::B, 3).unwrap();
+        assert_eq!(benchmark.iterations, 3);
+        assert!(benchmark.parse.min <= benchmark.parse.max);
+
+        let timings = Timings::from_samples(vec![Duration::from_nanos(7)]);
+        let benchmark = Benchmark {
+            parse: timings,
+            solve: timings,
+            ..benchmark
+        };
+        assert_eq!(
+            to_json(&[benchmark]),
+            "[\n  {\"day\": 9, \"part\": \"b\", \"iterations\": 3, \
+            \"parse_ns\": {\"min\": 7, \"median\": 7, \"max\": 7}, \
+            \"solve_ns\": {\"min\": 7, \"median\": 7, \"max\": 7}}\n]"
+        );
+    }
+}
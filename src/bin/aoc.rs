@@ -0,0 +1,300 @@
+// Runs any of the solved days from one binary, for example `aoc run 7 --part b` or
+// `aoc run --all`. Input is read from `--input`, which may be `-` for stdin, or from the data
+// directory set by `AOC_DATA_DIR`. `aoc verify` checks every answer in `answers.toml` and
+// `aoc bench` times parsing and solving, optionally as JSON. `aoc check` lists anything odd
+// about a day's input.
+
+use std::error::Error;
+use std::io::Error as IoError;
+use std::process::ExitCode;
+
+use adventofcode::answers::{self, ANSWERS_PATH};
+use adventofcode::bench::{self, Benchmark, Timings};
+use adventofcode::{input, solved_day, Part, DAYS};
+
+const USAGE: &str = "Usage:
+    aoc run <day> [--part a|b] [--input <path>|-]
+    aoc run --all [--part a|b]
+    aoc verify [--answers <path>]
+    aoc check <day>|--all [--input <path>|-]
+    aoc bench <day>|--all [--part a|b] [--input <path>|-] [--iterations <count>] [--json]";
+
+/// Iterations used by `aoc bench` when not given.
+const DEFAULT_ITERATIONS: usize = 10;
+
+enum Command {
+    Run(Run),
+
+    /// Check the answers in the registry at the path.
+    Verify(String),
+
+    Bench(Run),
+
+    /// Only the days and input are used.
+    Check(Run),
+}
+
+/// What to run, taken from the command line.
+struct Run {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+
+    /// Only allowed when running a single day.
+    input_path: Option<String>,
+
+    /// Only used when benchmarking.
+    iterations: usize,
+
+    /// Only used when benchmarking.
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(run) => run_days(&run),
+        Command::Verify(answers_path) => verify(&answers_path),
+        Command::Bench(run) => bench_days(&run),
+        Command::Check(run) => check_days(&run),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, Box<dyn Error>> {
+    match args.first().map(String::as_str) {
+        Some("run") => Ok(Command::Run(parse_run_args(&args[1..], false)?)),
+        Some("bench") => Ok(Command::Bench(parse_run_args(&args[1..], true)?)),
+        Some("check") => Ok(Command::Check(parse_run_args(&args[1..], false)?)),
+        Some("verify") => match &args[1..] {
+            [] => Ok(Command::Verify(ANSWERS_PATH.to_owned())),
+            [flag, path] if flag == "--answers" => Ok(Command::Verify(path.clone())),
+            _ => Err(IoError::other("unknown arguments to verify").into()),
+        },
+        Some(command) => Err(IoError::other(format!("unknown command '{command}'")).into()),
+        None => Err(IoError::other("missing command").into()),
+    }
+}
+
+fn parse_run_args(args: &[String], benchmarking: bool) -> Result<Run, Box<dyn Error>> {
+    let mut args = args.iter();
+
+    let mut days = Vec::new();
+    let mut parts = Part::ALL.to_vec();
+    let mut input_path = None;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut json = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => days = DAYS.iter().map(|solved| solved.day).collect(),
+            "--part" => {
+                let part = args.next().ok_or(IoError::other("missing part"))?;
+                parts = vec![part.parse()?];
+            }
+            "--input" => {
+                let path = args.next().ok_or(IoError::other("missing input path"))?;
+                input_path = Some(path.clone());
+            }
+            "--iterations" if benchmarking => {
+                let count = args.next().ok_or(IoError::other("missing iterations"))?;
+                iterations = count.parse()?;
+            }
+            "--json" if benchmarking => json = true,
+            day => {
+                let day: u8 = day
+                    .parse()
+                    .map_err(|_| IoError::other(format!("unknown argument '{day}'")))?;
+                if solved_day(day).is_none() {
+                    return Err(IoError::other(format!("day {day} is not solved")).into());
+                }
+                days.push(day);
+            }
+        }
+    }
+
+    if days.is_empty() {
+        return Err(IoError::other("missing day").into());
+    }
+    if input_path.is_some() && days.len() > 1 {
+        return Err(IoError::other("an input path can only be used with a single day").into());
+    }
+    Ok(Run {
+        days,
+        parts,
+        input_path,
+        iterations,
+        json,
+    })
+}
+
+fn run_days(run: &Run) -> Result<(), Box<dyn Error>> {
+    let mut rows = Vec::new();
+    for day in &run.days {
+        let solve = solved_day(*day).expect("solved day").solve;
+        let contents = input::read(*day, run.input_path.as_deref())?;
+        for part in &run.parts {
+            rows.push((*day, *part, solve(&contents, *part)?));
+        }
+    }
+
+    let rows: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(|(day, part, answer)| vec![day.to_string(), part.to_string(), answer])
+        .collect();
+    print_table(&["Day", "Part", "Answer"], &rows);
+    Ok(())
+}
+
+fn check_days(run: &Run) -> Result<(), Box<dyn Error>> {
+    for day in &run.days {
+        let check = solved_day(*day).expect("solved day").check;
+        let contents = input::read(*day, run.input_path.as_deref())?;
+        let warnings = check(&contents)?;
+        if warnings.is_empty() {
+            println!("day {day}: no warnings");
+        }
+        for warning in warnings {
+            println!("day {day}: {warning}");
+        }
+    }
+    Ok(())
+}
+
+fn verify(answers_path: &str) -> Result<(), Box<dyn Error>> {
+    let answers = answers::load(answers_path)?;
+    let mut mismatches = 0;
+    let rows: Vec<Vec<String>> = answers
+        .iter()
+        .map(|answer| {
+            let (actual, status) = match answer.solve() {
+                Ok(actual) if actual == answer.answer => (actual, "ok".to_owned()),
+                Ok(actual) => (actual, "MISMATCH".to_owned()),
+                Err(error) => (String::new(), format!("ERROR {error}")),
+            };
+            if status != "ok" {
+                mismatches += 1;
+            }
+            vec![
+                answer.day.to_string(),
+                answer.part.to_string(),
+                answer.input.display().to_string(),
+                answer.answer.clone(),
+                actual,
+                status,
+            ]
+        })
+        .collect();
+    print_table(
+        &["Day", "Part", "Input", "Expected", "Actual", "Status"],
+        &rows,
+    );
+
+    if mismatches > 0 {
+        return Err(IoError::other(format!(
+            "{mismatches} of {} answers did not match",
+            answers.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+fn bench_days(run: &Run) -> Result<(), Box<dyn Error>> {
+    let mut benchmarks: Vec<Benchmark> = Vec::new();
+    for day in &run.days {
+        let bench = solved_day(*day).expect("solved day").bench;
+        let contents = input::read(*day, run.input_path.as_deref())?;
+        for part in &run.parts {
+            benchmarks.push(bench(&contents, *part, run.iterations)?);
+        }
+    }
+
+    if run.json {
+        println!("{}", bench::to_json(&benchmarks));
+        return Ok(());
+    }
+
+    let timings = |timings: Timings| {
+        [timings.min, timings.median, timings.max].map(|duration| format!("{duration:.1?}"))
+    };
+    let rows: Vec<Vec<String>> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let mut row = vec![benchmark.day.to_string(), benchmark.part.to_string()];
+            row.extend(timings(benchmark.parse));
+            row.extend(timings(benchmark.solve));
+            row
+        })
+        .collect();
+    println!("{} iterations each", run.iterations.max(1));
+    print_table(
+        &[
+            "Day",
+            "Part",
+            "Parse min",
+            "Parse median",
+            "Parse max",
+            "Solve min",
+            "Solve median",
+            "Solve max",
+        ],
+        &rows,
+    );
+    Ok(())
+}
+
+/// Print rows under the headings, lining up the columns. Columns of numbers are right aligned.
+fn print_table(headings: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = (0..headings.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([headings[column].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let numeric: Vec<bool> = (0..headings.len())
+        .map(|column| rows.iter().all(|row| row[column].parse::<f64>().is_ok()))
+        .collect();
+
+    let format_row = |cells: Vec<String>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .enumerate()
+            .map(|(column, cell)| {
+                let width = widths[column];
+                if numeric[column] {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect();
+        cells.join("  ").trim_end().to_owned()
+    };
+    println!(
+        "{}",
+        format_row(headings.iter().map(|h| h.to_string()).collect())
+    );
+    println!(
+        "{}",
+        format_row(widths.iter().map(|width| "-".repeat(*width)).collect())
+    );
+    for row in rows {
+        println!("{}", format_row(row.clone()));
+    }
+}
@@ -0,0 +1,43 @@
+// Prints the value of seeds in every category from seed to location and the mapping used at each
+// step, for debugging almanacs by hand. Every seed in the almanac is traced unless `--seed` is
+// given, and `--csv` prints one row per seed instead.
+//
+//     day05trace [--seed <seed>] [--csv] [<input path>|-]
+
+use std::error::Error;
+use std::io::Error as IoError;
+
+use adventofcode::day05::{Day05, Id, Trace};
+use adventofcode::{input, Solution};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut seed: Option<Id> = None;
+    let mut csv = false;
+    let mut input_path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or(IoError::other("missing seed"))?;
+                seed = Some(value.parse()?);
+            }
+            "--csv" => csv = true,
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let contents = input::read(Day05::DAY, input_path.as_deref())?;
+    let almanac = Day05::parse(&contents)?;
+    let traces = match seed {
+        Some(seed) => vec![almanac.trace(seed, "seed", "location")?],
+        None => almanac.trace_seeds(),
+    };
+
+    if csv {
+        print!("{}", Trace::to_csv(&traces));
+    } else {
+        let traces: Vec<String> = traces.iter().map(Trace::to_string).collect();
+        println!("{}", traces.join("\n\n"));
+    }
+    Ok(())
+}
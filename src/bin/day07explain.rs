@@ -0,0 +1,37 @@
+// Prints every hand in the order it finished, with its type, the cards the jokers stood in for
+// and what it won, for checking the ranking by hand. `--jokers` plays part two and `--csv`
+// prints CSV instead.
+//
+//     day07explain [--jokers] [--csv] [<input path>|-]
+
+use std::error::Error;
+
+use adventofcode::day07::{Day07, Explanation, Rules};
+use adventofcode::{input, Solution};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut rules = Rules::standard();
+    let mut csv = false;
+    let mut input_path = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--jokers" => rules = Rules::jokers(),
+            "--csv" => csv = true,
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let contents = input::read(Day07::DAY, input_path.as_deref())?;
+    let deals = Day07::parse(&contents)?;
+    let explanations = Explanation::all(&deals, &rules)?;
+    if csv {
+        print!("{}", Explanation::to_csv(&explanations));
+    } else {
+        for explanation in &explanations {
+            println!("{explanation}");
+        }
+        let total: u32 = explanations.iter().map(|e| e.winnings).sum();
+        println!("Total: {total}");
+    }
+    Ok(())
+}
@@ -0,0 +1,16 @@
+// Prints how many hands there are of each type in part one and part two, and how many hands
+// changed from each type to each other type when jokers became wild.
+//
+//     day07stats [<input path>|-]
+
+use std::error::Error;
+
+use adventofcode::day07::{Day07, Distribution};
+use adventofcode::{input, Solution};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let contents = input::read_from_args(Day07::DAY)?;
+    let deals = Day07::parse(&contents)?;
+    println!("{}", Distribution::new(&deals)?);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// Prints the network as a Graphviz digraph with the start nodes green and the end nodes red.
+// `--ghost` outlines every node the walk from that start goes through. Render it with something
+// like `dot -Tsvg`.
+//
+//     day08dot [--ghost <start node>] [<input path>|-]
+
+use std::error::Error;
+use std::io::Error as IoError;
+
+use adventofcode::day08::{Day08, Graph};
+use adventofcode::{input, Solution};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut ghost = None;
+    let mut input_path = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ghost" => ghost = Some(args.next().ok_or(IoError::other("missing start node"))?),
+            _ => input_path = Some(arg),
+        }
+    }
+
+    let contents = input::read(Day08::DAY, input_path.as_deref())?;
+    let (instructions, nodes) = Day08::parse(&contents)?;
+    let graph = Graph::new(&nodes);
+    let visited = match ghost {
+        Some(id) => {
+            let start = graph
+                .index(&id)
+                .ok_or_else(|| IoError::other(format!("no node '{id}'")))?;
+            if instructions.is_empty() {
+                return Err(IoError::other("no instructions").into());
+            }
+            graph.visited(&instructions, start)
+        }
+        None => Vec::new(),
+    };
+    print!("{}", graph.to_dot(&visited));
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::error::Error;
+
+use crate::error::{Line, ParseError};
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<String>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        // Only digits
+        let to_digits = |line: &str| line.chars().filter_map(|c| c.to_digit(10)).collect();
+        Ok(total(lines, to_digits)?)
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(total(lines, line_to_digits)?)
+    }
+}
+
+/// Sum of the numbers made from the first and last digits of each line.
+fn total(lines: &[String], to_digits: fn(&str) -> Vec<u32>) -> Result<u32, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, text)| {
+            let digits = to_digits(text);
+            match (digits.first(), digits.last()) {
+                (Some(first), Some(last)) => Ok(first * 10 + last),
+                _ => {
+                    let line = Line {
+                        day: Day01::DAY,
+                        number: index + 1,
+                        text,
+                    };
+                    Err(line.missing_after(text, "digit"))
+                }
+            }
+        })
+        .sum()
+}
+
+/// Find a possible digit for each character. The words may overlap.
+fn line_to_digits(line: &str) -> Vec<u32> {
+    let chars = line.chars().collect::<Vec<char>>();
+    (0..chars.len())
+        .filter_map(|start_pos| match chars[start_pos..] {
+            ['0', ..] | ['z', 'e', 'r', 'o', ..] => Some(0),
+            ['1', ..] | ['o', 'n', 'e', ..] => Some(1),
+            ['2', ..] | ['t', 'w', 'o', ..] => Some(2),
+            ['3', ..] | ['t', 'h', 'r', 'e', 'e', ..] => Some(3),
+            ['4', ..] | ['f', 'o', 'u', 'r', ..] => Some(4),
+            ['5', ..] | ['f', 'i', 'v', 'e', ..] => Some(5),
+            ['6', ..] | ['s', 'i', 'x', ..] => Some(6),
+            ['7', ..] | ['s', 'e', 'v', 'e', 'n', ..] => Some(7),
+            ['8', ..] | ['e', 'i', 'g', 'h', 't', ..] => Some(8),
+            ['9', ..] | ['n', 'i', 'n', 'e', ..] => Some(9),
+            _ => None,
+        })
+        .collect::<Vec<u32>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    #[test]
+    fn example_part_one() {
+        let data = "1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet";
+        let lines = Day01::parse(data).unwrap();
+        let total = Day01::part_one(&lines).unwrap();
+        assert_eq!(total, 142);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let data = "two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen";
+        let lines = Day01::parse(data).unwrap();
+        let total = Day01::part_two(&lines).unwrap();
+        assert_eq!(total, 281);
+    }
+
+    #[test]
+    fn line_without_digits() {
+        let lines = Day01::parse("1abc2\nabc").unwrap();
+        let error = total(&lines, line_to_digits).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.kind, ParseErrorKind::Missing("digit"));
+    }
+}
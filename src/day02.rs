@@ -0,0 +1,223 @@
+use std::error::Error;
+
+use crate::error::{Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(games: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let bag = CubeCount::new(12, 13, 14);
+        let games = games.iter().filter(|game| {
+            game.counts
+                .iter()
+                .all(|count| count.none_greater_than(&bag))
+        });
+        let total = games.map(|game| game.id).sum();
+        Ok(total)
+    }
+
+    fn part_two(games: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let total = games.iter().map(|game| game.min_cubes().product()).sum();
+        Ok(total)
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    counts: Vec<CubeCount>,
+}
+
+impl Game {
+    fn min_cubes(&self) -> CubeCount {
+        self.counts
+            .iter()
+            .fold(CubeCount::default(), |acc, count| acc.max(count))
+    }
+}
+
+#[repr(u8)]
+enum Cube {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+
+impl Cube {
+    fn from_name(name: &str) -> Option<Cube> {
+        match name {
+            "red" => Some(Cube::Red),
+            "green" => Some(Cube::Green),
+            "blue" => Some(Cube::Blue),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CubeCount {
+    /// The discriminants of Cube are the indexes into the array.
+    count: [u32; 3],
+}
+
+impl CubeCount {
+    pub fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self::default()
+            .increment(Cube::Red, red)
+            .increment(Cube::Green, green)
+            .increment(Cube::Blue, blue)
+    }
+
+    /// All counts must be less than or equal to the counts in the other.
+    fn none_greater_than(&self, other: &Self) -> bool {
+        self.count
+            .iter()
+            .zip(other.count.iter())
+            .all(|(a, b)| a <= b)
+    }
+
+    fn increment(&self, cube: Cube, amount: u32) -> Self {
+        let mut cube_count = self.clone();
+        cube_count.count[cube as usize] += amount;
+        cube_count
+    }
+
+    fn max(&self, other: &CubeCount) -> Self {
+        let mut count = [0_u32; 3];
+        for (i, this_count) in self.count.into_iter().enumerate() {
+            count[i] = this_count.max(other.count[i]);
+        }
+        Self { count }
+    }
+
+    fn product(&self) -> u32 {
+        self.count.iter().product()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    fn parse_line(line: Line) -> Result<Game, ParseError> {
+        let mut parts = line.text.trim().split_terminator(&[':', ';']);
+
+        // Game
+        let game_part = parts
+            .next()
+            .ok_or(line.missing_after(line.text, "'Game'"))?;
+        let Some(game_id) = game_part.strip_prefix("Game ") else {
+            return Err(line.error(game_part, ParseErrorKind::Missing("'Game'")));
+        };
+        let game_id: u32 = line.number(game_id)?;
+
+        // Counts
+        let mut cube_counts = Vec::new();
+        for count_spec in parts {
+            let mut cube_count = CubeCount::default();
+            for cube_spec in count_spec.split(',') {
+                let cube_spec = cube_spec.trim();
+                if let Some((count_str, cube_name)) = cube_spec.split_once(' ') {
+                    let count: u32 = line.number(count_str)?;
+                    let cube = Cube::from_name(cube_name)
+                        .ok_or(line.error(cube_name, ParseErrorKind::Unexpected("cube")))?;
+                    cube_count = cube_count.increment(cube, count);
+                } else {
+                    return Err(line.error(cube_spec, ParseErrorKind::Missing("cube count")));
+                }
+            }
+            cube_counts.push(cube_count);
+        }
+
+        Ok(Game {
+            id: game_id,
+            counts: cube_counts,
+        })
+    }
+
+    // Vec<Result<...>> to Result<Vec<...>>
+    Line::all(Day02::DAY, input)
+        .map(parse_line)
+        .collect::<Result<Vec<Game>, ParseError>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn parse_example() {
+        let games = parse(EXAMPLE).expect("example");
+        assert_eq!(games.len(), 5);
+        let game1 = games.first().unwrap();
+        assert_eq!(game1.id, 1);
+        assert_eq!(game1.counts.len(), 3);
+        assert_eq!(game1.counts[0].count, [4, 0, 3]);
+        assert_eq!(game1.counts[1].count, [1, 2, 6]);
+        assert_eq!(game1.counts[2].count, [0, 2, 0]);
+        let game5 = games.get(4).unwrap();
+        assert_eq!(game5.id, 5);
+        assert_eq!(game5.counts.len(), 2);
+        assert_eq!(game5.counts[0].count, [6, 3, 1]);
+        assert_eq!(game5.counts[1].count, [1, 2, 2]);
+    }
+
+    #[test]
+    fn parse_unknown_cube() {
+        let error = parse("Game 1: 3 blue\nGame 2: 1 red, 4 pink").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 18));
+        assert_eq!(error.snippet, "pink");
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("cube"));
+    }
+
+    #[test]
+    fn read_example() {
+        let contents = read_to_string("data/day02.txt").expect("file");
+        let lines = contents.lines();
+        assert_eq!(lines.count(), 100);
+    }
+
+    #[test]
+    fn example_min_cubes() {
+        let games = parse(EXAMPLE).expect("example");
+        let min_cubes: Vec<CubeCount> = games.iter().map(|game| game.min_cubes()).collect();
+        assert_eq!(min_cubes[0], CubeCount::new(4, 2, 6));
+        assert_eq!(min_cubes[1], CubeCount::new(1, 3, 4));
+        assert_eq!(min_cubes[2], CubeCount::new(20, 13, 6));
+        assert_eq!(min_cubes[3], CubeCount::new(14, 3, 15));
+        assert_eq!(min_cubes[4], CubeCount::new(6, 3, 2));
+    }
+
+    #[test]
+    fn product() {
+        let product = CubeCount::new(4, 2, 6).product();
+        assert_eq!(product, 48);
+    }
+
+    #[test]
+    fn example_part_one() {
+        let games = Day02::parse(EXAMPLE).expect("example");
+        let answer = Day02::part_one(&games).expect("answer");
+        assert_eq!(answer, 8);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let games = Day02::parse(EXAMPLE).expect("example");
+        let answer = Day02::part_two(&games).expect("answer");
+        assert_eq!(answer, 2286);
+    }
+}
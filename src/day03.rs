@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::error::{Line, ParseError};
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Schematic;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(schematic: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(schematic
+            .parts
+            .iter()
+            .filter(|part| schematic.any_symbol_touches(part))
+            .map(|part| part.number)
+            .sum())
+    }
+
+    fn part_two(schematic: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let symbol_to_part = schematic
+            .parts
+            .iter()
+            .flat_map(|part| {
+                schematic
+                    .symbols_adjacent(part)
+                    .into_iter()
+                    .map(move |symbol| (symbol, part))
+            })
+            .filter(|(symbol, _)| symbol.is_gear());
+
+        let mut gears_to_parts: HashMap<&Symbol, Vec<&Part>> = HashMap::new();
+        for (symbol, part) in symbol_to_part {
+            gears_to_parts
+                .entry(symbol)
+                .and_modify(|parts| parts.push(part))
+                .or_insert(vec![part]);
+        }
+
+        // Gear ratios
+        let ratios = gears_to_parts.into_values().filter_map(|parts| {
+            if parts.len() == 2 {
+                Some(parts.first().unwrap().number * parts.last().unwrap().number)
+            } else {
+                None
+            }
+        });
+
+        Ok(ratios.sum())
+    }
+}
+
+#[derive(Eq, Hash, PartialEq)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+impl Coord {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Debug for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("({}, {})", self.x, self.y))
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("({}, {})", self.x, self.y))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Part {
+    coord: Coord,
+    number: u32,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "#{}({}, {})",
+            self.number, self.coord.x, self.coord.y
+        ))
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Symbol {
+    name: char,
+    coord: Coord,
+}
+
+impl Symbol {
+    fn is_gear(&self) -> bool {
+        self.name == '*'
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    parts: Vec<Part>,
+    symbols: Vec<Symbol>,
+}
+
+impl Schematic {
+    fn any_symbol_touches(&self, part: &Part) -> bool {
+        !self.symbols_adjacent(part).is_empty()
+    }
+
+    fn symbols_adjacent(&self, part: &Part) -> Vec<&Symbol> {
+        // Symbol must be in this range to touch.
+        let x_min = part.coord.x;
+        let x_max = x_min + part.number.to_string().len();
+        let x_range = (x_min as i32 - 1)..=(x_max as i32);
+        let y_range = (part.coord.y as i32 - 1)..=(part.coord.y as i32 + 1);
+
+        self.symbols
+            .iter()
+            .filter(|symbol| {
+                x_range.contains(&(symbol.coord.x as i32))
+                    && y_range.contains(&(symbol.coord.y as i32))
+            })
+            .collect::<Vec<&Symbol>>()
+    }
+}
+
+fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let mut parts = Vec::new();
+    let mut symbols = Vec::new();
+
+    for line in Line::all(Day03::DAY, input) {
+        let y = line.number - 1;
+        let text = line.text.trim();
+        let mut chars_iter = text.char_indices().peekable();
+        while let Some((x, c)) = chars_iter.next() {
+            match c {
+                '.' => (),
+                '0'..='9' => {
+                    let mut end = x + 1;
+                    while let Some((_, peek_char)) = chars_iter.peek() {
+                        if peek_char.is_ascii_digit() {
+                            end += 1;
+                            chars_iter.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    parts.push(Part {
+                        coord: Coord::new(x, y),
+                        number: line.number(&text[x..end])?,
+                    });
+                }
+                _ => {
+                    symbols.push(Symbol {
+                        name: c,
+                        coord: Coord::new(x, y),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(Schematic { parts, symbols })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    const EXAMPLE: &str = "467..114..
+         ...*......
+         ..35..633.
+         ......#...
+         617*......
+         .....+.58.
+         ..592.....
+         ......755.
+         ...$.*....
+         .664.598..";
+
+    #[test]
+    fn example_part_one() {
+        let schematic = parse(EXAMPLE).expect("schematic");
+        let answer = Day03::part_one(&schematic).expect("answer");
+        assert_eq!(answer, 4361);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let schematic = parse(EXAMPLE).expect("schematic");
+        let answer = Day03::part_two(&schematic).expect("answer");
+        assert_eq!(answer, 467835);
+    }
+
+    #[test]
+    fn example_parse() {
+        let schematic = parse(EXAMPLE).expect("schematic");
+        assert_eq!(
+            schematic.parts.first(),
+            Some(&Part {
+                coord: Coord::new(0, 0),
+                number: 467
+            })
+        );
+        let first_symbol = schematic.symbols.first().map(|symbol| &symbol.coord);
+        assert_eq!(first_symbol, Some(&Coord::new(3, 1)));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let schematic = parse("153..\n....*").expect("schematic");
+        let answer = Day03::part_one(&schematic).expect("answer");
+        assert_eq!(answer, 0);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let schematic = parse("100.200.\n...*....").expect("schematic");
+        let answer = Day03::part_two(&schematic).expect("answer");
+        assert_eq!(answer, 100 * 200);
+    }
+
+    #[test]
+    fn test_parse() {
+        let schematic = parse("123").expect("schematic");
+        assert_eq!(
+            schematic.parts.first(),
+            Some(&Part {
+                coord: Coord::new(0, 0),
+                number: 123
+            })
+        );
+
+        let schematic = parse("153..\n....*").expect("schematic");
+        assert_eq!(
+            schematic.parts.first(),
+            Some(&Part {
+                coord: Coord::new(0, 0),
+                number: 153
+            })
+        );
+        let first_symbol = schematic.symbols.first().map(|symbol| &symbol.coord);
+        assert_eq!(first_symbol, Some(&Coord::new(4, 1)));
+    }
+
+    #[test]
+    fn parse_number_too_large() {
+        let error = parse("..99999999999*").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn touching() {
+        let schematic = parse("153..\n....*").expect("schematic");
+        let part = schematic.parts.first().expect("part");
+        assert!(!schematic.any_symbol_touches(part));
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+
+use crate::error::{Line, ParseError};
+use crate::Solution;
+
+pub struct Day04;
+
+/// Winning numbers and the numbers on the card.
+pub type Game = (Vec<u32>, Vec<u32>);
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(games: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(games
+            .iter()
+            .map(|game| (1 << (winning_count(game) as u32)) >> 1)
+            .sum())
+    }
+
+    fn part_two(games: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let mut counts = vec![1_u32; games.len()];
+
+        for (id, game) in games.iter().enumerate() {
+            // Add duplicates
+            for dup_index in (id + 1)..=(id + winning_count(game)) {
+                if dup_index < counts.len() {
+                    counts[dup_index] += counts[id];
+                }
+            }
+        }
+
+        Ok(counts.iter().sum())
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    Line::all(Day04::DAY, input)
+        .map(|line| {
+            let (_, numbers) = line
+                .text
+                .split_once(':')
+                .ok_or(line.missing_after(line.text, "':'"))?;
+            let (winning, card) = numbers
+                .split_once('|')
+                .ok_or(line.missing_after(numbers, "'|'"))?;
+            Ok((line.numbers(winning)?, line.numbers(card)?))
+        })
+        .collect()
+}
+
+/// Number of card numbers that are also winning numbers.
+fn winning_count(game: &Game) -> usize {
+    let winning: BTreeSet<u32> = game.0.iter().copied().collect();
+    let card: BTreeSet<u32> = game.1.iter().copied().collect();
+    winning.intersection(&card).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn example_part_one() {
+        let games = parse(EXAMPLE).unwrap();
+        let answer = Day04::part_one(&games).expect("answer");
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let games = parse(EXAMPLE).unwrap();
+        let answer = Day04::part_two(&games).expect("answer");
+        assert_eq!(answer, 30);
+    }
+
+    #[test]
+    fn parse_missing_pipe() {
+        let error = parse("Card 1: 41 48 | 83\nCard 2: 13 32 61").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 17));
+        assert_eq!(error.kind, ParseErrorKind::Missing("'|'"));
+    }
+
+    #[test]
+    fn part_one_basic() {
+        let games = parse("Game 1: 2 | 3").unwrap();
+        assert_eq!(Day04::part_one(&games).unwrap(), 0);
+        let games = parse("Game 1: 2 | 2").unwrap();
+        assert_eq!(Day04::part_one(&games).unwrap(), 1);
+        let games = parse("Game 1: 2 3 | 2 3").unwrap();
+        assert_eq!(Day04::part_one(&games).unwrap(), 2);
+    }
+
+    #[test]
+    fn part_two_basic() {
+        let games = parse("Game 1: 2 | 3").unwrap();
+        assert_eq!(Day04::part_two(&games).unwrap(), 1);
+        let games = parse("Game 1: 2 | 2\nGame 2: 2 | 2").unwrap();
+        assert_eq!(Day04::part_two(&games).unwrap(), 3);
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::io::Error as IoError;
+use std::ops::Range;
+
+use crate::error::{missing_line, Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+mod compose;
+mod inverse;
+mod ranges;
+mod trace;
+mod validate;
+
+pub use compose::{Piece, PiecewiseMap};
+pub use trace::{Step, Trace};
+pub use validate::Warning;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+    type Answer = Id;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(almanac: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        // Use `day05trace` to see the whole path of each seed.
+        let location = almanac
+            .seeds
+            .iter()
+            .map(|seed| *path(*seed, almanac.seed_to_location()).last().unwrap())
+            .min()
+            .ok_or(IoError::other("no seeds"))?;
+        Ok(location)
+    }
+
+    fn part_two(almanac: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let location = almanac
+            .min_location(almanac.seed_ranges())
+            .ok_or(IoError::other("no seeds"))?;
+        Ok(location)
+    }
+
+    fn warnings(almanac: &Self::Input) -> Vec<String> {
+        almanac.validate().iter().map(Warning::to_string).collect()
+    }
+}
+
+pub type Id = usize;
+
+/// What an id means, such as "seed" or "soil".
+pub type Category = String;
+
+/// Where part one starts and ends.
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+type Map = Vec<Mapping>;
+
+#[derive(Debug)]
+struct Mapping {
+    source_start: Id,
+    destination_start: Id,
+    count: usize,
+}
+
+impl Mapping {
+    /// How many ids the mapping moves. A mapping whose source or destination would end past the
+    /// largest id stops there, the same as a seed range.
+    fn len(&self) -> usize {
+        self.count
+            .min(Id::MAX - self.source_start)
+            .min(Id::MAX - self.destination_start)
+    }
+
+    fn source_range(&self) -> Range<Id> {
+        self.source_start..self.source_start + self.len()
+    }
+
+    fn destination(&self, location: Id) -> Option<Id> {
+        let offset = location.checked_sub(self.source_start)?;
+        (offset < self.len()).then(|| offset + self.destination_start)
+    }
+}
+
+/// A map and the category it maps to.
+#[derive(Debug)]
+struct Link {
+    destination: Category,
+    map: Map,
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    /// Individual seeds in part one, pairs of start and length in part two.
+    seeds: Vec<Id>,
+
+    /// The maps keyed by the category they map from, so the order in the input doesn't matter.
+    /// If a source doesn't exist in a map then its destination is the same value.
+    links: BTreeMap<Category, Link>,
+}
+
+impl Almanac {
+    /// Every category named by a map, sorted.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = self
+            .links
+            .iter()
+            .flat_map(|(source, link)| [source.as_str(), link.destination.as_str()])
+            .collect();
+        categories.sort();
+        categories.dedup();
+        categories
+    }
+
+    /// The links to follow, in order, to get from one category to another.
+    fn route(&self, from: &str, to: &str) -> Result<Vec<&Link>, IoError> {
+        let categories = self.categories();
+        if let Some(unknown) = [from, to].into_iter().find(|c| !categories.contains(c)) {
+            return Err(IoError::other(format!(
+                "no maps use the category '{unknown}'"
+            )));
+        }
+
+        match self.follow(from, to) {
+            Ok(maps) => Ok(maps),
+            Err(_) if self.follow(to, from).is_ok() => Err(IoError::other(format!(
+                "'{to}' comes before '{from}' and maps only go forwards"
+            ))),
+            Err(Some(stop)) => Err(IoError::other(format!(
+                "no maps lead from '{from}' to '{to}', they stop at '{stop}'"
+            ))),
+            Err(None) => Err(IoError::other(format!(
+                "the maps from '{from}' go round in a circle without reaching '{to}'"
+            ))),
+        }
+    }
+
+    /// Follow the maps from one category until reaching another. Fails with the category the
+    /// maps stop at, or `None` if they go round in a circle.
+    fn follow(&self, from: &str, to: &str) -> Result<Vec<&Link>, Option<String>> {
+        let mut links = Vec::new();
+        let mut category = from;
+        while category != to {
+            let link = self
+                .links
+                .get(category)
+                .ok_or_else(|| Some(category.to_owned()))?;
+            if links.len() == self.links.len() {
+                return Err(None);
+            }
+            links.push(link);
+            category = &link.destination;
+        }
+        Ok(links)
+    }
+
+    /// The maps from seed to location, which are checked when the almanac is parsed.
+    fn seed_to_location(&self) -> Vec<&Map> {
+        let links = self
+            .route(SEED, LOCATION)
+            .expect("parse checks seeds lead to locations");
+        links.into_iter().map(|link| &link.map).collect()
+    }
+
+    /// Follow the maps from one category to another, such as from "soil" to "light".
+    pub fn translate(&self, value: Id, from: &str, to: &str) -> Result<Id, IoError> {
+        let maps = self.route(from, to)?.into_iter().map(|link| &link.map);
+        Ok(*path(value, maps).last().unwrap())
+    }
+
+    /// The seeds read as pairs of start and length. A range that would overflow stops at the
+    /// largest id.
+    fn seed_ranges(&self) -> Vec<Range<Id>> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|chunk| chunk[0]..chunk[0].saturating_add(chunk[1]))
+            .collect()
+    }
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let mut lines = Line::all(Day05::DAY, input).peekable();
+    let missing = |what| missing_line(Day05::DAY, input, what);
+
+    // First line is seeds.
+    let seeds_line = lines.next().ok_or_else(|| missing("seeds"))?;
+    let seeds = seeds_line
+        .text
+        .strip_prefix("seeds:")
+        .ok_or(seeds_line.error(seeds_line.text, ParseErrorKind::Missing("'seeds:'")))?;
+    let seeds: Vec<Id> = seeds_line.numbers(seeds)?;
+    let blank_line = lines.next().ok_or_else(|| missing("blank line"))?;
+    if !blank_line.text.trim().is_empty() {
+        return Err(blank_line.error(blank_line.text, ParseErrorKind::Missing("blank line")));
+    }
+
+    // Categories
+    let mut links = BTreeMap::new();
+    while let Some(map_id_line) = lines.next() {
+        let Some(map_id) = map_id_line.text.strip_suffix(" map:") else {
+            return Err(map_id_line.missing_after(map_id_line.text, "' map:'"));
+        };
+        let Some((source, destination)) = map_id.split_once("-to-") else {
+            return Err(map_id_line.missing_after(map_id, "'-to-'"));
+        };
+        if source.is_empty() {
+            return Err(map_id_line.error(source, ParseErrorKind::Missing("source category")));
+        }
+        if destination.is_empty() {
+            return Err(map_id_line.missing_after(map_id, "destination category"));
+        }
+        if links.contains_key(source) {
+            return Err(map_id_line.error(source, ParseErrorKind::Unexpected("second map from")));
+        }
+
+        // The category ends on a blank line.
+        let mut mappings = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+            // Destination, start, count
+            let mut numbers = line.text.split_whitespace();
+            let mut next_number = |what| match numbers.next() {
+                Some(number) => line.number::<Id>(number),
+                None => Err(line.missing_after(line.text, what)),
+            };
+            mappings.push(Mapping {
+                destination_start: next_number("destination")?,
+                source_start: next_number("source")?,
+                count: next_number("count")?,
+            });
+            if let Some(extra) = numbers.next() {
+                return Err(line.error(extra, ParseErrorKind::Unexpected("number")));
+            }
+        }
+        lines.next_if(|line| line.text.trim().is_empty());
+
+        let link = Link {
+            destination: destination.to_owned(),
+            map: mappings,
+        };
+        links.insert(source.to_owned(), link);
+    }
+
+    let almanac = Almanac { seeds, links };
+    if almanac.route(SEED, LOCATION).is_err() {
+        return Err(missing("maps from 'seed' to 'location'"));
+    }
+    Ok(almanac)
+}
+
+fn path<'a>(start: Id, maps: impl IntoIterator<Item = &'a Map>) -> Vec<Id> {
+    // FIXME: Fold
+    let mut path = Vec::new();
+    path.push(start);
+    for map in maps {
+        let location = path.last().unwrap();
+        let dest = map
+            .iter()
+            .find_map(|mapping| mapping.destination(*location))
+            .unwrap_or(*location);
+        path.push(dest);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+
+    /// The almanac in the puzzle description.
+    pub(super) fn example() -> Almanac {
+        let contents = read_to_string("data/day05example.txt").expect("file");
+        parse(&contents).expect("almanac")
+    }
+
+    #[test]
+    fn example_part_one() {
+        let contents = read_to_string("data/day05example.txt").expect("file");
+        let almanac = parse(&contents).expect("almanac");
+        let answer = Day05::part_one(&almanac).expect("answer");
+        assert_eq!(answer, 35);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let contents = read_to_string("data/day05example.txt").expect("file");
+        let almanac = parse(&contents).expect("almanac");
+        let answer = Day05::part_two(&almanac).expect("answer");
+        assert_eq!(answer, 46);
+    }
+
+    #[test]
+    fn overflowing_mappings() {
+        // Both mappings stop at the largest id, so the last seed keeps its value.
+        let almanac = parse(&format!(
+            "seeds: {} 1 5 2\n\nseed-to-location map:\n0 {} 2\n{} 5 3",
+            Id::MAX - 1,
+            Id::MAX - 1,
+            Id::MAX - 1
+        ))
+        .expect("almanac");
+        assert_eq!(Day05::part_one(&almanac).expect("answer"), 0);
+        assert_eq!(Day05::part_two(&almanac).expect("answer"), 0);
+        assert_eq!(almanac.translate(5, SEED, LOCATION).unwrap(), Id::MAX - 1);
+        assert_eq!(almanac.translate(6, SEED, LOCATION).unwrap(), 6);
+        assert_eq!(
+            almanac.location_ranges(almanac.seed_ranges()),
+            vec![0..1, Id::MAX - 1..Id::MAX, 6..7]
+        );
+        assert_eq!(almanac.composed().get(Id::MAX - 1), 0);
+    }
+
+    #[test]
+    fn parse_short_mapping() {
+        let error = parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 6));
+        assert_eq!(error.kind, ParseErrorKind::Missing("count"));
+    }
+
+    #[test]
+    fn maps_in_any_order() {
+        let contents = read_to_string("data/day05example.txt").expect("file");
+        let mut blocks: Vec<&str> = contents.trim_end().split("\n\n").collect();
+        blocks[1..].reverse();
+        let almanac = parse(&blocks.join("\n\n")).expect("almanac");
+        assert_eq!(Day05::part_one(&almanac).expect("answer"), 35);
+        assert_eq!(almanac.translate(79, "seed", "soil").unwrap(), 81);
+        assert_eq!(almanac.translate(81, "soil", "location").unwrap(), 82);
+        assert_eq!(almanac.translate(5, "water", "water").unwrap(), 5);
+    }
+
+    #[test]
+    fn translate_errors() {
+        let almanac = parse(
+            "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-location map:\n\ndust-to-soil map:",
+        )
+        .expect("almanac");
+        let error = |from, to| almanac.translate(1, from, to).unwrap_err().to_string();
+        assert_eq!(
+            error("soil", "seed"),
+            "'seed' comes before 'soil' and maps only go forwards"
+        );
+        assert_eq!(
+            error("seed", "dust"),
+            "no maps lead from 'seed' to 'dust', they stop at 'location'"
+        );
+        assert_eq!(error("seed", "water"), "no maps use the category 'water'");
+        assert_eq!(
+            almanac.categories(),
+            vec!["dust", "location", "seed", "soil"]
+        );
+    }
+
+    #[test]
+    fn parse_broken_chain() {
+        let error = parse("seeds: 1\n\nseed-to-soil map:\n\nwater-to-location map:").unwrap_err();
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Missing("maps from 'seed' to 'location'")
+        );
+
+        let error = parse("seeds: 1\n\nseed-to-soil map:\n\nseed-to-water map:").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("second map from"));
+
+        let error = parse("seeds: 1\n\nseed-soil map:").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 10));
+        assert_eq!(error.kind, ParseErrorKind::Missing("'-to-'"));
+    }
+
+    #[test]
+    fn parse_missing_map_name() {
+        let error = parse("seeds: 79 14\n\n50 98 2").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert_eq!(error.kind, ParseErrorKind::Missing("' map:'"));
+    }
+}
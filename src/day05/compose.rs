@@ -0,0 +1,245 @@
+//! Composing maps into a single piecewise function so a lookup is one binary search no matter
+//! how many maps there are.
+
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+use std::ops::Range;
+
+use super::{Almanac, Id, Map};
+
+/// Ids in the source range move to the same position in the range starting at
+/// `destination_start`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Piece {
+    pub source: Range<Id>,
+    pub destination_start: Id,
+}
+
+impl Piece {
+    pub fn destination(&self) -> Range<Id> {
+        self.destination_start..self.destination_start + self.source.len()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.source.start == self.destination_start
+    }
+}
+
+impl Display for Piece {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} -> {:?}", self.source, self.destination())
+    }
+}
+
+/// A function from ids to ids made of pieces that don't overlap, sorted by source. Ids that
+/// aren't in any piece keep their value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PiecewiseMap {
+    pieces: Vec<Piece>,
+}
+
+impl PiecewiseMap {
+    /// Every id keeps its value.
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Where mappings overlap the first one wins, the same as a lookup in the map.
+    pub(super) fn from_map(map: &Map) -> Self {
+        let mut pieces: Vec<Piece> = Vec::new();
+        for mapping in map {
+            let mut uncovered = vec![mapping.source_range()];
+            for piece in &pieces {
+                uncovered = uncovered
+                    .into_iter()
+                    .flat_map(|range| {
+                        [
+                            range.start..range.end.min(piece.source.start),
+                            range.start.max(piece.source.end)..range.end,
+                        ]
+                    })
+                    .filter(|range| !range.is_empty())
+                    .collect();
+            }
+            pieces.extend(uncovered.into_iter().map(|source| Piece {
+                destination_start: mapping.destination(source.start).unwrap(),
+                source,
+            }));
+        }
+        Self::from_pieces(pieces)
+    }
+
+    /// Sort the pieces, dropping the ones that don't change anything and joining the ones
+    /// that continue each other.
+    fn from_pieces(mut pieces: Vec<Piece>) -> Self {
+        pieces.retain(|piece| !piece.source.is_empty() && !piece.is_identity());
+        pieces.sort_by_key(|piece| piece.source.start);
+        let mut joined: Vec<Piece> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match joined.last_mut() {
+                Some(last)
+                    if last.source.end == piece.source.start
+                        && last.destination().end == piece.destination_start =>
+                {
+                    last.source.end = piece.source.end;
+                }
+                _ => joined.push(piece),
+            }
+        }
+        Self { pieces: joined }
+    }
+
+    pub fn pieces(&self) -> &[Piece] {
+        &self.pieces
+    }
+
+    /// Binary search for the piece holding the id.
+    pub fn get(&self, id: Id) -> Id {
+        let index = self.pieces.partition_point(|piece| piece.source.end <= id);
+        match self.pieces.get(index) {
+            Some(piece) if piece.source.contains(&id) => {
+                piece.destination_start + (id - piece.source.start)
+            }
+            _ => id,
+        }
+    }
+
+    /// Every id from zero up, including the gaps between pieces where ids keep their value.
+    pub(super) fn segments(&self) -> impl Iterator<Item = Piece> + '_ {
+        let mut next_start = 0;
+        let mut pieces = self.pieces.iter().peekable();
+        std::iter::from_fn(move || {
+            if next_start == Id::MAX {
+                return None;
+            }
+            let segment = match pieces.peek() {
+                Some(piece) if piece.source.start == next_start => pieces.next().unwrap().clone(),
+                Some(piece) => Piece {
+                    source: next_start..piece.source.start,
+                    destination_start: next_start,
+                },
+                None => Piece {
+                    source: next_start..Id::MAX,
+                    destination_start: next_start,
+                },
+            };
+            next_start = segment.source.end;
+            Some(segment)
+        })
+    }
+
+    /// This function followed by `next`.
+    pub fn then(&self, next: &PiecewiseMap) -> PiecewiseMap {
+        let mut pieces = Vec::new();
+        for segment in self.segments() {
+            let destination = segment.destination();
+            let next_segments = next
+                .segments()
+                .skip_while(|next_segment| next_segment.source.end <= destination.start)
+                .take_while(|next_segment| next_segment.source.start < destination.end);
+            for next_segment in next_segments {
+                let start = destination.start.max(next_segment.source.start);
+                let end = destination.end.min(next_segment.source.end);
+                let source_start = segment.source.start + (start - destination.start);
+                pieces.push(Piece {
+                    source: source_start..source_start + (end - start),
+                    destination_start: next_segment.destination_start
+                        + (start - next_segment.source.start),
+                });
+            }
+        }
+        Self::from_pieces(pieces)
+    }
+}
+
+impl Display for PiecewiseMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.pieces.is_empty() {
+            return writeln!(f, "identity");
+        }
+        for piece in &self.pieces {
+            writeln!(f, "{piece}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Every map composed into one function from seed to location.
+    pub fn composed(&self) -> PiecewiseMap {
+        compose(self.seed_to_location())
+    }
+
+    /// Compose the maps from one category to another, such as from "soil" to "water".
+    pub fn compose(&self, from: &str, to: &str) -> Result<PiecewiseMap, IoError> {
+        let links = self.route(from, to)?;
+        Ok(compose(links.into_iter().map(|link| &link.map)))
+    }
+}
+
+fn compose<'a>(maps: impl IntoIterator<Item = &'a Map>) -> PiecewiseMap {
+    maps.into_iter()
+        .fold(PiecewiseMap::identity(), |composed, map| {
+            composed.then(&PiecewiseMap::from_map(map))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::example;
+    use super::super::{path, Mapping};
+    use super::*;
+
+    #[test]
+    fn composed_matches_path() {
+        let almanac = example();
+        let composed = almanac.composed();
+        for seed in 0..120 {
+            assert_eq!(
+                composed.get(seed),
+                *path(seed, almanac.seed_to_location()).last().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn overlapping_mappings() {
+        let map = vec![
+            Mapping {
+                destination_start: 100,
+                source_start: 0,
+                count: 10,
+            },
+            Mapping {
+                destination_start: 200,
+                source_start: 5,
+                count: 10,
+            },
+        ];
+        let function = PiecewiseMap::from_map(&map);
+        assert_eq!(
+            function.to_string(),
+            "0..10 -> 100..110\n10..15 -> 205..210\n"
+        );
+        assert_eq!(function.get(7), 107);
+        assert_eq!(function.get(12), 207);
+        assert_eq!(function.get(15), 15);
+    }
+
+    #[test]
+    fn compose_sub_chain() {
+        let almanac = example();
+        let seed_to_soil = almanac.compose("seed", "soil").unwrap();
+        assert_eq!(
+            seed_to_soil,
+            PiecewiseMap::from_map(&almanac.links["seed"].map)
+        );
+        assert_eq!(
+            seed_to_soil.to_string(),
+            "50..98 -> 52..100\n98..100 -> 50..52\n"
+        );
+        let light = almanac.compose("light", "light").unwrap();
+        assert_eq!(light, PiecewiseMap::identity());
+        assert!(almanac.compose("location", "seed").is_err());
+    }
+}
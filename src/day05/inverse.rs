@@ -0,0 +1,115 @@
+//! Looking up which seeds lead to a location, the reverse of following the maps.
+
+use std::ops::Range;
+
+use super::{Almanac, Id, PiecewiseMap};
+
+impl PiecewiseMap {
+    /// Every id that ends up in the range, as sorted ranges that don't overlap. More than one id
+    /// can end up in the same place when a mapping moves ids on top of ids that keep their value.
+    pub fn preimage(&self, range: Range<Id>) -> Vec<Range<Id>> {
+        let mut sources: Vec<Range<Id>> = self
+            .segments()
+            .filter_map(|segment| {
+                let destination = segment.destination();
+                let start = destination.start.max(range.start);
+                let end = destination.end.min(range.end);
+                let source_start = segment.source.start + (start - destination.start);
+                (start < end).then(|| source_start..source_start + (end - start))
+            })
+            .collect();
+
+        sources.sort_by_key(|source| source.start);
+        let mut joined: Vec<Range<Id>> = Vec::with_capacity(sources.len());
+        for source in sources {
+            match joined.last_mut() {
+                Some(last) if last.end == source.start => last.end = source.end,
+                _ => joined.push(source),
+            }
+        }
+        joined
+    }
+}
+
+impl Almanac {
+    /// Every seed that leads to a location in the range, whether or not the almanac lists it.
+    pub fn seeds_for_locations(&self, locations: Range<Id>) -> Vec<Range<Id>> {
+        self.composed().preimage(locations)
+    }
+
+    /// Every seed that leads to the location, whether or not the almanac lists it.
+    pub fn seeds_for_location(&self, location: Id) -> Vec<Id> {
+        self.seeds_for_locations(location..location + 1)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// The lowest seed in the ranges that leads to the lowest location, with that location.
+    /// Works backwards from the location found going forwards, so it doubles as a check on it.
+    pub fn seed_for_min_location(
+        &self,
+        seed_ranges: impl IntoIterator<Item = Range<Id>>,
+    ) -> Option<(Id, Id)> {
+        let seed_ranges: Vec<Range<Id>> = seed_ranges.into_iter().collect();
+        let location = self.min_location(seed_ranges.iter().cloned())?;
+        let seed = self
+            .seeds_for_locations(location..location + 1)
+            .iter()
+            .flat_map(|seeds| {
+                seed_ranges.iter().filter_map(|listed| {
+                    let start = seeds.start.max(listed.start);
+                    (start < seeds.end.min(listed.end)).then_some(start)
+                })
+            })
+            .min()?;
+        Some((seed, location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::example;
+    use super::super::{path, Mapping};
+    use super::*;
+
+    #[test]
+    fn preimage_of_overlap() {
+        // 10..15 keep their value and 0..5 are moved on top of them.
+        let map = vec![Mapping {
+            destination_start: 10,
+            source_start: 0,
+            count: 5,
+        }];
+        let function = PiecewiseMap::from_map(&map);
+        assert_eq!(function.preimage(12..20), vec![2..5, 12..20]);
+        assert_eq!(function.preimage(0..5), vec![]);
+    }
+
+    #[test]
+    fn seeds_lead_back() {
+        let almanac = example();
+        for location in 0..120 {
+            let seeds = almanac.seeds_for_location(location);
+            for seed in seeds {
+                assert_eq!(
+                    *path(seed, almanac.seed_to_location()).last().unwrap(),
+                    location
+                );
+            }
+        }
+        assert!(almanac.seeds_for_location(35).contains(&13));
+    }
+
+    #[test]
+    fn example_seed_for_min_location() {
+        let almanac = example();
+        let seeds = almanac.seeds.iter().map(|&seed| seed..seed + 1);
+        assert_eq!(almanac.seed_for_min_location(seeds), Some((13, 35)));
+        assert_eq!(
+            almanac.seed_for_min_location(almanac.seed_ranges()),
+            Some((82, 46))
+        );
+        assert_eq!(almanac.seed_for_min_location([]), None);
+    }
+}
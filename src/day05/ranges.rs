@@ -0,0 +1,115 @@
+//! Pushing whole ranges of ids through the maps instead of one id at a time.
+//!
+//! A range is split wherever it crosses the edge of a mapping, so the number of ranges grows
+//! with the number of mappings rather than with the number of ids.
+
+use std::ops::Range;
+
+use super::{Almanac, Id, Map};
+
+/// Find where every id in the ranges ends up after one map. The order of the ranges that come
+/// out is not meaningful and they may overlap if the mappings overlap.
+pub fn map_ranges(map: &Map, ranges: impl IntoIterator<Item = Range<Id>>) -> Vec<Range<Id>> {
+    let mut unmapped: Vec<Range<Id>> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+    let mut mapped = Vec::with_capacity(unmapped.len());
+
+    // Like a single lookup, the first mapping to cover an id wins.
+    for mapping in map {
+        let source = mapping.source_range();
+        let mut remaining = Vec::with_capacity(unmapped.len());
+        for range in unmapped {
+            let start = range.start.max(source.start);
+            let end = range.end.min(source.end);
+            if start >= end {
+                remaining.push(range);
+                continue;
+            }
+
+            let destination = mapping.destination(start).expect("start is in the mapping");
+            mapped.push(destination..destination + (end - start));
+            if range.start < start {
+                remaining.push(range.start..start);
+            }
+            if end < range.end {
+                remaining.push(end..range.end);
+            }
+        }
+        unmapped = remaining;
+    }
+
+    // Anything not covered by a mapping keeps its value.
+    mapped.extend(unmapped);
+    mapped
+}
+
+impl Almanac {
+    /// Every location reachable from the seed ranges, as ranges.
+    pub fn location_ranges(
+        &self,
+        seed_ranges: impl IntoIterator<Item = Range<Id>>,
+    ) -> Vec<Range<Id>> {
+        let seed_ranges: Vec<Range<Id>> = seed_ranges.into_iter().collect();
+        self.seed_to_location()
+            .into_iter()
+            .fold(seed_ranges, |ranges, map| map_ranges(map, ranges))
+    }
+
+    /// The lowest location for any seed in the ranges, or `None` if the ranges are empty.
+    pub fn min_location(&self, seed_ranges: impl IntoIterator<Item = Range<Id>>) -> Option<Id> {
+        self.location_ranges(seed_ranges)
+            .iter()
+            .map(|range| range.start)
+            .min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::example;
+    use super::super::{path, Mapping};
+    use super::*;
+
+    #[test]
+    fn split_at_mapping_edges() {
+        let map = vec![Mapping {
+            destination_start: 100,
+            source_start: 10,
+            count: 5,
+        }];
+        let mut ranges = map_ranges(&map, [5..12, 13..20]);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, vec![5..10, 15..20, 100..102, 103..105]);
+    }
+
+    #[test]
+    fn first_mapping_wins() {
+        let map = vec![
+            Mapping {
+                destination_start: 100,
+                source_start: 0,
+                count: 10,
+            },
+            Mapping {
+                destination_start: 200,
+                source_start: 5,
+                count: 10,
+            },
+        ];
+        let mut ranges = map_ranges(&map, Some(0..15));
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, vec![100..110, 205..210]);
+    }
+
+    #[test]
+    fn same_as_one_at_a_time() {
+        let almanac = example();
+        for seed_range in almanac.seed_ranges() {
+            let expected = seed_range
+                .clone()
+                .map(|seed| *path(seed, almanac.seed_to_location()).last().unwrap())
+                .min();
+            assert_eq!(almanac.min_location([seed_range]), expected);
+        }
+        assert_eq!(almanac.min_location([]), None);
+    }
+}
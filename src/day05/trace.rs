@@ -0,0 +1,157 @@
+//! Following a value through the maps one step at a time, recording which mapping moved it,
+//! for debugging almanacs by hand.
+
+use std::fmt::{Display, Formatter, Write};
+use std::io::Error as IoError;
+
+use super::{Almanac, Category, Id, Mapping, LOCATION, SEED};
+
+impl Display for Mapping {
+    /// The same as in the input.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_start, self.source_start, self.count
+        )
+    }
+}
+
+/// The value after following one map.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub category: Category,
+    pub value: Id,
+
+    /// The mapping that moved the value, numbered from one under its map's header, and the
+    /// mapping as written. `None` if no mapping covered the value so it kept it.
+    pub mapping: Option<(usize, String)>,
+}
+
+/// The value in every category from where the trace started to where it finished.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace {
+    pub category: Category,
+    pub value: Id,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    /// The value in the last category.
+    pub fn end(&self) -> Id {
+        self.steps.last().map_or(self.value, |step| step.value)
+    }
+
+    /// A header naming each category followed by a column with the number of the mapping used
+    /// to get there, then one row per trace. Assumes every trace follows the same categories.
+    pub fn to_csv(traces: &[Trace]) -> String {
+        let mut csv = String::new();
+        if let Some(first) = traces.first() {
+            csv.push_str(&first.category);
+            for step in &first.steps {
+                write!(csv, ",{0},{0} mapping", step.category).unwrap();
+            }
+            csv.push('\n');
+        }
+        for trace in traces {
+            write!(csv, "{}", trace.value).unwrap();
+            for step in &trace.steps {
+                let number = step.mapping.as_ref().map(|(number, _)| number.to_string());
+                write!(csv, ",{},{}", step.value, number.unwrap_or_default()).unwrap();
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl Display for Trace {
+    /// One category per line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.category, self.value)?;
+        for step in &self.steps {
+            write!(f, "\n{} {}", step.category, step.value)?;
+            match &step.mapping {
+                Some((number, mapping)) => write!(f, " (mapping {number}: {mapping})")?,
+                None => write!(f, " (not mapped)")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    /// Follow a value from one category to another, recording every step.
+    pub fn trace(&self, value: Id, from: &str, to: &str) -> Result<Trace, IoError> {
+        let mut trace = Trace {
+            category: from.to_owned(),
+            value,
+            steps: Vec::new(),
+        };
+        for link in self.route(from, to)? {
+            let value = trace.end();
+            let step = link
+                .map
+                .iter()
+                .enumerate()
+                .find_map(|(index, mapping)| {
+                    Some(Step {
+                        category: link.destination.clone(),
+                        value: mapping.destination(value)?,
+                        mapping: Some((index + 1, mapping.to_string())),
+                    })
+                })
+                .unwrap_or_else(|| Step {
+                    category: link.destination.clone(),
+                    value,
+                    mapping: None,
+                });
+            trace.steps.push(step);
+        }
+        Ok(trace)
+    }
+
+    /// Trace every seed listed in the almanac to its location, reading them as single seeds
+    /// like part one.
+    pub fn trace_seeds(&self) -> Vec<Trace> {
+        self.seeds
+            .iter()
+            .map(|&seed| {
+                self.trace(seed, SEED, LOCATION)
+                    .expect("parse checks seeds lead to locations")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_trace() {
+        let trace = example().trace(79, "seed", "fertilizer").unwrap();
+        assert_eq!(
+            trace.to_string(),
+            "seed 79\nsoil 81 (mapping 2: 52 50 48)\nfertilizer 81 (not mapped)"
+        );
+        assert_eq!(trace.end(), 81);
+    }
+
+    #[test]
+    fn example_csv() {
+        let traces = example().trace_seeds();
+        let ends: Vec<Id> = traces.iter().map(Trace::end).collect();
+        assert_eq!(ends, vec![82, 43, 86, 35]);
+
+        let csv = Trace::to_csv(&traces[..1]);
+        assert_eq!(
+            csv,
+            "seed,soil,soil mapping,fertilizer,fertilizer mapping,water,water mapping,\
+            light,light mapping,temperature,temperature mapping,humidity,humidity mapping,\
+            location,location mapping\n\
+            79,81,2,81,,81,,74,2,78,3,78,,82,1\n"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Checking an almanac for things that are allowed but probably not meant, such as mappings
+//! that overlap so only the first one is ever used.
+
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use super::{Almanac, Category, Id, Mapping};
+
+/// Mappings are numbered from one in the order they appear under their map's header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// Part two reads the seeds as pairs so the last one has no length.
+    OddSeedCount(usize),
+
+    /// A seed range that ends past the largest id. It's cut short at the largest id.
+    SeedRangeOverflow { start: Id, length: Id },
+
+    /// A mapping whose source or destination ends past the largest id. It's cut short at the
+    /// largest id and left out of the other checks.
+    MappingOverflow { map: Category, mapping: usize },
+
+    /// Sources in both mappings, which go where the first one says.
+    Overlap {
+        map: Category,
+        first: usize,
+        second: usize,
+        sources: Range<Id>,
+    },
+
+    /// One mapping carries on where the other ends, so they could be written as one.
+    Mergeable {
+        map: Category,
+        first: usize,
+        second: usize,
+    },
+
+    /// Sources between two mappings, which keep their value.
+    Gap { map: Category, sources: Range<Id> },
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::OddSeedCount(count) => {
+                write!(
+                    f,
+                    "{count} seeds can't be read as pairs of start and length"
+                )
+            }
+            Warning::SeedRangeOverflow { start, length } => {
+                write!(
+                    f,
+                    "seed range starting at {start} with length {length} overflows"
+                )
+            }
+            Warning::MappingOverflow { map, mapping } => {
+                write!(f, "{map} map: mapping {mapping} overflows")
+            }
+            Warning::Overlap {
+                map,
+                first,
+                second,
+                sources,
+            } => write!(
+                f,
+                "{map} map: mappings {first} and {second} overlap at {sources:?}"
+            ),
+            Warning::Mergeable { map, first, second } => {
+                write!(
+                    f,
+                    "{map} map: mappings {first} and {second} could be merged"
+                )
+            }
+            Warning::Gap { map, sources } => {
+                write!(f, "{map} map: {sources:?} is not mapped")
+            }
+        }
+    }
+}
+
+impl Mapping {
+    /// The source and destination ranges, or `None` if either goes past the largest id.
+    fn checked_ranges(&self) -> Option<(Range<Id>, Range<Id>)> {
+        let source_end = self.source_start.checked_add(self.count)?;
+        let destination_end = self.destination_start.checked_add(self.count)?;
+        Some((
+            self.source_start..source_end,
+            self.destination_start..destination_end,
+        ))
+    }
+}
+
+impl Almanac {
+    /// Everything suspicious about the almanac. The seeds come first, then the maps sorted by
+    /// their header.
+    pub fn validate(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        if self.seeds.len() % 2 == 1 {
+            warnings.push(Warning::OddSeedCount(self.seeds.len()));
+        }
+        for pair in self.seeds.chunks_exact(2) {
+            if pair[0].checked_add(pair[1]).is_none() {
+                warnings.push(Warning::SeedRangeOverflow {
+                    start: pair[0],
+                    length: pair[1],
+                });
+            }
+        }
+
+        for (source, link) in &self.links {
+            let map = format!("{source}-to-{}", link.destination);
+
+            // Numbered mappings sorted by source.
+            let mut mappings = Vec::with_capacity(link.map.len());
+            for (index, mapping) in link.map.iter().enumerate() {
+                match mapping.checked_ranges() {
+                    Some((source, destination)) => mappings.push((index + 1, source, destination)),
+                    None => warnings.push(Warning::MappingOverflow {
+                        map: map.clone(),
+                        mapping: index + 1,
+                    }),
+                }
+            }
+            mappings.sort_by_key(|(_, source, _)| source.start);
+
+            for (position, (first, source, destination)) in mappings.iter().enumerate() {
+                for (second, other_source, _) in &mappings[position + 1..] {
+                    if other_source.start >= source.end {
+                        break;
+                    }
+                    let sources = other_source.start..source.end.min(other_source.end);
+                    warnings.push(Warning::Overlap {
+                        map: map.clone(),
+                        first: *first.min(second),
+                        second: *first.max(second),
+                        sources,
+                    });
+                }
+
+                let Some((next, next_source, next_destination)) = mappings.get(position + 1) else {
+                    continue;
+                };
+                if source.end == next_source.start && destination.end == next_destination.start {
+                    warnings.push(Warning::Mergeable {
+                        map: map.clone(),
+                        first: *first.min(next),
+                        second: *first.max(next),
+                    });
+                }
+            }
+
+            // Gaps are whatever isn't covered between the lowest and highest source.
+            let mut covered_to = mappings.first().map_or(0, |(_, source, _)| source.start);
+            for (_, source, _) in &mappings {
+                if source.start > covered_to {
+                    warnings.push(Warning::Gap {
+                        map: map.clone(),
+                        sources: covered_to..source.start,
+                    });
+                }
+                covered_to = covered_to.max(source.end);
+            }
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::super::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_warnings() {
+        let warnings: Vec<String> = example()
+            .validate()
+            .iter()
+            .map(Warning::to_string)
+            .collect();
+        assert_eq!(
+            warnings,
+            vec!["soil-to-fertilizer map: mappings 1 and 2 could be merged"]
+        );
+    }
+
+    #[test]
+    fn overlaps_gaps_and_overflow() {
+        let almanac = parse(&format!(
+            "seeds: 1 {} 7\n\n\
+            seed-to-location map:\n\
+            100 0 10\n\
+            200 5 10\n\
+            300 20 5\n\
+            0 {} 2",
+            Id::MAX,
+            Id::MAX - 1
+        ))
+        .expect("almanac");
+        assert_eq!(
+            almanac.validate(),
+            vec![
+                Warning::OddSeedCount(3),
+                Warning::SeedRangeOverflow {
+                    start: 1,
+                    length: Id::MAX
+                },
+                Warning::MappingOverflow {
+                    map: "seed-to-location".to_owned(),
+                    mapping: 4
+                },
+                Warning::Overlap {
+                    map: "seed-to-location".to_owned(),
+                    first: 1,
+                    second: 2,
+                    sources: 5..10
+                },
+                Warning::Gap {
+                    map: "seed-to-location".to_owned(),
+                    sources: 15..20
+                },
+            ]
+        );
+    }
+}
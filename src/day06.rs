@@ -0,0 +1,286 @@
+use std::error::Error;
+use std::io::Error as IoError;
+use std::ops::RangeInclusive;
+
+use crate::error::{missing_line, Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+mod physics;
+
+pub use physics::{Boat, Physics};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Races;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(races: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        races.winning_product(Reading::Spaced)
+    }
+
+    fn part_two(races: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        races.winning_product(Reading::Kerned)
+    }
+}
+
+/// How to read the numbers on the sheet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Reading {
+    /// Each column of numbers is a separate race, like part one.
+    Spaced,
+
+    /// The spaces between the numbers are ignored so there is only one race, like part two.
+    Kerned,
+}
+
+/// The same sheet of paper read both ways. The numbers can be too large to read kerned, which
+/// only matters when reading it that way.
+#[derive(Debug)]
+pub struct Races {
+    spaced: Vec<Race>,
+    kerned: Result<Race, ParseError>,
+}
+
+impl Races {
+    pub fn read(&self, reading: Reading) -> Result<&[Race], ParseError> {
+        match reading {
+            Reading::Spaced => Ok(&self.spaced),
+            Reading::Kerned => self
+                .kerned
+                .as_ref()
+                .map(std::slice::from_ref)
+                .map_err(Clone::clone),
+        }
+    }
+
+    /// The number of ways to win each race multiplied together.
+    pub fn winning_product(&self, reading: Reading) -> Result<u128, Box<dyn Error>> {
+        self.read(reading)?
+            .iter()
+            .try_fold(1_u128, |product, race| {
+                product.checked_mul(race.winning_count())
+            })
+            .ok_or_else(|| IoError::other("the product of the ways to win overflows").into())
+    }
+}
+
+/// Its methods use the puzzle's physics. [`Physics`] answers the same questions for others.
+///
+/// A distance too far to fit in a `u128` beats any record, so every time limit that can be read
+/// works.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Race {
+    pub time_limit: u128,
+    pub record_distance: u128,
+}
+
+impl Race {
+    /// The shortest and longest charge times that beat the record, or `None` if none do.
+    ///
+    /// The distance is `charge * (limit - charge)`, a parabola peaking halfway through the race,
+    /// so it only grows up to halfway and a binary search finds the shortest charge time that
+    /// beats the record. The rest is symmetric.
+    pub fn winning_interval(&self) -> Option<RangeInclusive<u128>> {
+        let beats = |charge_time| {
+            self.distance(charge_time)
+                .is_none_or(|distance| distance > self.record_distance)
+        };
+        let halfway = self.time_limit / 2;
+        if !beats(halfway) {
+            return None;
+        }
+
+        // Charging for `longer` always beats the record.
+        let (mut shorter, mut longer) = (0, halfway);
+        while shorter < longer {
+            let middle = shorter + (longer - shorter) / 2;
+            if beats(middle) {
+                longer = middle;
+            } else {
+                shorter = middle + 1;
+            }
+        }
+        Some(longer..=self.time_limit - longer)
+    }
+
+    /// How many charge times beat the record.
+    pub fn winning_count(&self) -> u128 {
+        self.winning_interval()
+            .map_or(0, |times| times.end() - times.start() + 1)
+    }
+
+    /// How far the boat goes, or `None` if it's too far to count.
+    fn distance(&self, charge_time: u128) -> Option<u128> {
+        let velocity = charge_time;
+        (self.time_limit - charge_time).checked_mul(velocity)
+    }
+}
+
+fn parse(input: &str) -> Result<Races, ParseError> {
+    /// The line and the numbers after its colon.
+    fn number_line<'a>(
+        lines: &mut impl Iterator<Item = Line<'a>>,
+        input: &str,
+        what: &'static str,
+    ) -> Result<(Line<'a>, &'a str), ParseError> {
+        let line = lines
+            .next()
+            .ok_or_else(|| missing_line(Day06::DAY, input, what))?;
+        let (_, numbers) = line
+            .text
+            .split_once(':')
+            .ok_or(line.missing_after(line.text, "':'"))?;
+        Ok((line, numbers))
+    }
+
+    let mut lines = Line::all(Day06::DAY, input);
+    let (time_line, time_limits) = number_line(&mut lines, input, "time limits")?;
+    let (distance_line, record_distances) = number_line(&mut lines, input, "record distances")?;
+
+    // Every time needs a distance in the same column.
+    let spaced_times: Vec<u128> = time_line.numbers(time_limits)?;
+    let spaced_distances: Vec<u128> = distance_line.numbers(record_distances)?;
+    if let Some(extra) = record_distances.split_whitespace().nth(spaced_times.len()) {
+        return Err(distance_line.error(extra, ParseErrorKind::Unexpected("record distance")));
+    }
+    if spaced_distances.len() < spaced_times.len() {
+        return Err(distance_line.missing_after(distance_line.text, "record distance"));
+    }
+
+    let spaced = spaced_times
+        .into_iter()
+        .zip(spaced_distances)
+        .map(|(time_limit, record_distance)| Race {
+            time_limit,
+            record_distance,
+        })
+        .collect();
+
+    let kerned_number = |line: Line, numbers: &str| {
+        numbers
+            .replace(' ', "")
+            .parse::<u128>()
+            .map_err(|_| line.error(numbers.trim(), ParseErrorKind::InvalidNumber))
+    };
+    let kerned = kerned_number(time_line, time_limits).and_then(|time_limit| {
+        Ok(Race {
+            time_limit,
+            record_distance: kerned_number(distance_line, record_distances)?,
+        })
+    });
+
+    Ok(Races { spaced, kerned })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    #[test]
+    fn example_part_one() {
+        let races = parse(EXAMPLE).unwrap();
+        let answer = Day06::part_one(&races).unwrap();
+        assert_eq!(answer, 288);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let races = parse(EXAMPLE).unwrap();
+        let answer = Day06::part_two(&races).unwrap();
+        assert_eq!(answer, 71503);
+    }
+
+    #[test]
+    fn winning_interval() {
+        let race = |time_limit, record_distance| Race {
+            time_limit,
+            record_distance,
+        };
+        assert_eq!(race(7, 9).winning_interval(), Some(2..=5));
+
+        // Charging for 10 or 20 only equals the record.
+        assert_eq!(race(30, 200).winning_interval(), Some(11..=19));
+        assert_eq!(race(30, 225).winning_interval(), None);
+        assert_eq!(race(30, 224).winning_interval(), Some(15..=15));
+        assert_eq!(race(0, 0).winning_count(), 0);
+
+        let limit = u128::MAX;
+        let interval = race(limit, limit - 1).winning_interval();
+        assert_eq!(interval, Some(2..=limit - 2));
+        assert_eq!(race(limit, 0).winning_count(), limit - 1);
+    }
+
+    #[test]
+    fn parse_missing_distances() {
+        let error = parse("Time:      7  15   30").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.kind, ParseErrorKind::Missing("record distances"));
+    }
+
+    #[test]
+    fn both_readings() {
+        let races = parse(EXAMPLE).unwrap();
+        assert_eq!(races.read(Reading::Spaced).unwrap().len(), 3);
+        let kerned = Race {
+            time_limit: 71530,
+            record_distance: 940200,
+        };
+        assert_eq!(races.read(Reading::Kerned).unwrap(), [kerned]);
+    }
+
+    #[test]
+    fn too_large_to_kern() {
+        let races = parse(&format!(
+            "Time: {}\nDistance: {}",
+            ["4000000000"; 4].join(" "),
+            ["10"; 4].join(" ")
+        ))
+        .unwrap();
+        assert_eq!(Day06::part_one(&races).unwrap(), 3999999999_u128.pow(4));
+        let error = races.read(Reading::Kerned).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn kerned_past_u64() {
+        // A 12 digit time and a 23 digit record.
+        let races = parse("Time: 1000000 000000\nDistance: 2000000000 0000000000000").unwrap();
+        let kerned = Race {
+            time_limit: 1_000_000_000_000,
+            record_distance: 20_000_000_000_000_000_000_000,
+        };
+        assert_eq!(races.read(Reading::Kerned).unwrap(), [kerned]);
+        assert_eq!(
+            kerned.winning_interval(),
+            Some(20_416_847_669..=979_583_152_331)
+        );
+        assert_eq!(Day06::part_two(&races).unwrap(), 959_166_304_663);
+    }
+
+    #[test]
+    fn parse_mismatched_columns() {
+        let error = parse("Time:      7  15   30\nDistance:  9  40  200  5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 24));
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("record distance"));
+
+        let error = parse("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 17));
+        assert_eq!(error.kind, ParseErrorKind::Missing("record distance"));
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        let error = parse("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(error.snippet, "4O");
+    }
+}
@@ -0,0 +1,162 @@
+//! Other ways boats could move, for trying variants of the puzzle with the same questions.
+
+use std::cmp::Reverse;
+
+use super::Race;
+
+/// How far a boat goes in a race.
+pub trait Physics {
+    /// Distance covered by the end of the race when the button is held for `charge_time`, or
+    /// `None` if it's too far to count.
+    fn distance(&self, charge_time: u128, time_limit: u128) -> Option<u128>;
+
+    /// How many charge times beat the record. Tries every charge time so models with a closed
+    /// form should override it.
+    fn winning_count(&self, race: &Race) -> u128 {
+        (0..=race.time_limit)
+            .filter(|&charge_time| {
+                self.distance(charge_time, race.time_limit)
+                    .is_none_or(|distance| distance > race.record_distance)
+            })
+            .count() as u128
+    }
+
+    /// The charge time that goes furthest, the shortest if several tie. Tries every charge
+    /// time like [`Physics::winning_count`].
+    fn optimal_charge_time(&self, time_limit: u128) -> u128 {
+        (0..=time_limit)
+            .max_by_key(|&charge_time| {
+                let distance = self.distance(charge_time, time_limit);
+                (distance.is_none(), distance, Reverse(charge_time))
+            })
+            .expect("at least one charge time")
+    }
+}
+
+/// A boat gains `acceleration` millimetres per millisecond of speed for every millisecond the
+/// button is held, up to `max_speed`. Once released it loses `drag` of its speed every
+/// millisecond until it stops.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Boat {
+    pub acceleration: u64,
+    pub drag: u64,
+    pub max_speed: Option<u64>,
+}
+
+impl Boat {
+    /// The boats in the puzzle.
+    pub const PUZZLE: Boat = Boat {
+        acceleration: 1,
+        drag: 0,
+        max_speed: None,
+    };
+
+    /// Without drag or a top speed the distance is a multiple of the puzzle's.
+    fn is_scaled_puzzle(&self) -> bool {
+        self.acceleration > 0 && self.drag == 0 && self.max_speed.is_none()
+    }
+
+    /// The same boat using the queries that try every charge time.
+    fn tried(self) -> impl Physics {
+        struct Tried(Boat);
+
+        impl Physics for Tried {
+            fn distance(&self, charge_time: u128, time_limit: u128) -> Option<u128> {
+                self.0.distance(charge_time, time_limit)
+            }
+        }
+
+        Tried(self)
+    }
+}
+
+impl Physics for Boat {
+    fn distance(&self, charge_time: u128, time_limit: u128) -> Option<u128> {
+        let speed = charge_time.checked_mul(u128::from(self.acceleration));
+        let speed = match self.max_speed {
+            Some(max_speed) => speed.map_or(u128::from(max_speed), |speed| {
+                speed.min(u128::from(max_speed))
+            }),
+            None => speed?,
+        };
+        let moving_time = time_limit.saturating_sub(charge_time);
+        if self.drag == 0 {
+            return speed.checked_mul(moving_time);
+        }
+
+        // The speed drops in steps until it would go below zero. What's lost is never more than
+        // the full speed would cover, so only that can overflow.
+        let drag = u128::from(self.drag);
+        let steps = moving_time.min(speed.div_ceil(drag));
+        let full_speed = steps.checked_mul(speed)?;
+        Some(full_speed - drag * (steps * steps.saturating_sub(1) / 2))
+    }
+
+    fn winning_count(&self, race: &Race) -> u128 {
+        if !self.is_scaled_puzzle() {
+            return self.tried().winning_count(race);
+        }
+
+        // Beating the record by a multiple is the same as beating the record divided by it.
+        let scaled = Race {
+            time_limit: race.time_limit,
+            record_distance: race.record_distance / u128::from(self.acceleration),
+        };
+        scaled.winning_count()
+    }
+
+    fn optimal_charge_time(&self, time_limit: u128) -> u128 {
+        if !self.is_scaled_puzzle() {
+            return self.tried().optimal_charge_time(time_limit);
+        }
+        time_limit / 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RACE: Race = Race {
+        time_limit: 30,
+        record_distance: 200,
+    };
+
+    #[test]
+    fn puzzle_physics() {
+        assert_eq!(Boat::PUZZLE.winning_count(&RACE), 9);
+        assert_eq!(Boat::PUZZLE.tried().winning_count(&RACE), 9);
+        assert_eq!(Boat::PUZZLE.optimal_charge_time(7), 3);
+        assert_eq!(Boat::PUZZLE.tried().optimal_charge_time(7), 3);
+    }
+
+    #[test]
+    fn faster_boats() {
+        let boat = Boat {
+            acceleration: 3,
+            ..Boat::PUZZLE
+        };
+        assert_eq!(boat.winning_count(&RACE), boat.tried().winning_count(&RACE));
+        assert_eq!(boat.winning_count(&RACE), 25);
+    }
+
+    #[test]
+    fn drag_and_max_speed() {
+        let boat = Boat {
+            acceleration: 2,
+            drag: 1,
+            max_speed: Some(10),
+        };
+        // Released at 10 after 5ms, then 10 + 9 + ... + 1 before stopping.
+        assert_eq!(boat.distance(5, 30), Some(55));
+        // Only 4ms left to move, 10 + 9 + 8 + 7.
+        assert_eq!(boat.distance(26, 30), Some(34));
+        assert_eq!(boat.optimal_charge_time(30), 5);
+        assert_eq!(boat.winning_count(&RACE), 0);
+        let race = Race {
+            time_limit: 30,
+            record_distance: 54,
+        };
+        assert_eq!(boat.winning_count(&race), 16);
+    }
+}
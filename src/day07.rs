@@ -0,0 +1,515 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+
+use crate::error::{Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+mod explain;
+mod poker;
+mod stats;
+
+pub use explain::Explanation;
+pub use stats::Distribution;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Deal>;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(deals: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(winnings(deals, &Rules::standard())?)
+    }
+
+    fn part_two(deals: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(winnings(deals, &Rules::jokers())?)
+    }
+}
+
+/// A line of input before the cards are ranked. The cards are known to be valid.
+#[derive(Debug)]
+pub struct Deal {
+    cards: String,
+    bid: u32,
+}
+
+/// The higher the number the better the card.
+pub type CardRank = u32;
+
+/// Straights and flushes only count in poker.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandRank {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    FiveOfAKind,
+}
+
+impl HandRank {
+    /// Every type of hand in Camel Cards, from weakest to strongest in the puzzle.
+    pub const CAMEL_CARDS: [HandRank; 7] = [
+        Self::HighCard,
+        Self::OnePair,
+        Self::TwoPair,
+        Self::ThreeOfAKind,
+        Self::FullHouse,
+        Self::FourOfAKind,
+        Self::FiveOfAKind,
+    ];
+
+    /// Every type of hand in poker without wild cards, from weakest to strongest.
+    pub const POKER: [HandRank; 9] = [
+        Self::HighCard,
+        Self::OnePair,
+        Self::TwoPair,
+        Self::ThreeOfAKind,
+        Self::Straight,
+        Self::Flush,
+        Self::FullHouse,
+        Self::FourOfAKind,
+        Self::StraightFlush,
+    ];
+
+    /// Classify a hand by its grouping, how many there are of each card with the largest count
+    /// first, which can't tell straights or flushes. The types are named for hands of five so
+    /// hands of any other size are classified by their two largest groups alone, and a group of
+    /// more than five is five of a kind.
+    pub fn from_grouping(grouping: &[usize]) -> Self {
+        let first = grouping.first().copied().unwrap_or(0);
+        let second = grouping.get(1).copied().unwrap_or(0);
+        match (first, second) {
+            (5.., _) => Self::FiveOfAKind,
+            (4, _) => Self::FourOfAKind,
+            (3, 2..) => Self::FullHouse,
+            (3, _) => Self::ThreeOfAKind,
+            (2, 2..) => Self::TwoPair,
+            (2, _) => Self::OnePair,
+            _ => Self::HighCard,
+        }
+    }
+}
+
+impl Display for HandRank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::HighCard => "high card",
+            Self::OnePair => "one pair",
+            Self::TwoPair => "two pair",
+            Self::ThreeOfAKind => "three of a kind",
+            Self::Straight => "straight",
+            Self::Flush => "flush",
+            Self::FullHouse => "full house",
+            Self::FourOfAKind => "four of a kind",
+            Self::StraightFlush => "straight flush",
+            Self::FiveOfAKind => "five of a kind",
+        };
+        f.pad(name)
+    }
+}
+
+/// Which cards beat which, which are wild and which types of hand beat which, so one engine
+/// plays both parts as well as variants such as deuces wild.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rules {
+    /// Every card from weakest to strongest.
+    order: Vec<char>,
+
+    /// Cards that stand in for whatever card makes the best hand. They keep their place in
+    /// `order` when breaking ties between hands of the same type.
+    wild: Vec<char>,
+
+    /// Every type of hand from weakest to strongest.
+    precedence: Vec<HandRank>,
+}
+
+impl Rules {
+    /// Part one, where nothing is wild.
+    pub fn standard() -> Self {
+        Self::new(CARDS, "").expect("valid rules")
+    }
+
+    /// Part two, where `J` is a joker. Jokers are wild but the weakest card in a tie.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J").expect("valid rules")
+    }
+
+    /// `order` has every card once, from weakest to strongest, and `wild` any of them.
+    pub fn new(order: &str, wild: &str) -> Result<Self, IoError> {
+        let mut sorted: Vec<char> = order.chars().collect();
+        sorted.sort_by_key(|card| CARDS.find(*card));
+        if sorted.iter().collect::<String>() != CARDS {
+            return Err(IoError::other(format!(
+                "card order '{order}' should have each of '{CARDS}' once"
+            )));
+        }
+        if let Some(card) = wild.chars().find(|card| !CARDS.contains(*card)) {
+            return Err(IoError::other(format!("unknown wild card '{card}'")));
+        }
+        Ok(Self {
+            order: order.chars().collect(),
+            wild: wild.chars().collect(),
+            precedence: HandRank::CAMEL_CARDS.to_vec(),
+        })
+    }
+
+    /// Change which types of hand beat which. `precedence` has every Camel Cards type once, from
+    /// weakest to strongest.
+    pub fn with_precedence(self, precedence: &[HandRank]) -> Result<Self, IoError> {
+        let mut sorted = precedence.to_vec();
+        sorted.sort();
+        if sorted != HandRank::CAMEL_CARDS {
+            return Err(IoError::other(format!(
+                "hand precedence {precedence:?} should have every Camel Cards type once"
+            )));
+        }
+        Ok(Self {
+            precedence: precedence.to_vec(),
+            ..self
+        })
+    }
+
+    /// Panics if the card isn't in the deck, which parsing prevents.
+    pub fn card_rank(&self, card: char) -> CardRank {
+        let index = self
+            .order
+            .iter()
+            .position(|c| *c == card)
+            .unwrap_or_else(|| unreachable!("card '{card}' should have been rejected when parsed"));
+        index as CardRank + 1
+    }
+
+    /// How many there are of each card, largest count first, once the wild cards are used to
+    /// make the best hand. Five cards can group as `[3, 2]` for a full house, for example.
+    pub fn grouping(&self, cards: &str) -> Result<Vec<usize>, IoError> {
+        if cards.is_empty() {
+            return Err(IoError::other("a hand needs at least one card"));
+        }
+        Ok(counts(&self.best_cards(cards)))
+    }
+
+    /// The cards with every wild card standing in for the card that makes the best hand, the
+    /// type latest in `precedence`. When several ways make that type, the one standing in the
+    /// strongest cards wins, so with the usual precedence every wild card joins the most common
+    /// other card, the strongest if several are as common.
+    pub fn best_cards(&self, cards: &str) -> String {
+        let wild_count = cards
+            .chars()
+            .filter(|card| self.wild.contains(card))
+            .count();
+        // Cards already in the hand, then as many others as there are wild cards to make new
+        // groups with, strongest first. Weaker cards outside the hand would make the same types.
+        let mut present: Vec<char> = cards
+            .chars()
+            .filter(|card| !self.wild.contains(card))
+            .collect();
+        present.sort_by_key(|card| Reverse(self.card_rank(*card)));
+        present.dedup();
+        let absent = self
+            .order
+            .iter()
+            .rev()
+            .filter(|card| !present.contains(card));
+        let mut candidates = present.clone();
+        candidates.extend(absent.take(wild_count));
+        candidates.sort_by_key(|card| Reverse(self.card_rank(*card)));
+
+        let substitute = |substitutes: &[char]| {
+            let mut substitutes = substitutes.iter();
+            cards
+                .chars()
+                .map(|card| {
+                    if self.wild.contains(&card) {
+                        *substitutes.next().unwrap()
+                    } else {
+                        card
+                    }
+                })
+                .collect::<String>()
+        };
+        let mut best: Option<((usize, Vec<CardRank>), String)> = None;
+        // Wild cards are interchangeable, so try each choice of stand-ins once.
+        let mut visit = |substitutes: &[char]| {
+            let best_cards = substitute(substitutes);
+            let rank = HandRank::from_grouping(&counts(&best_cards));
+            let ranks = substitutes.iter().map(|card| self.card_rank(*card));
+            let key = (self.strength(rank), ranks.collect());
+            if best.as_ref().is_none_or(|(best_key, _)| key > *best_key) {
+                best = Some((key, best_cards));
+            }
+        };
+        choices(&candidates, wild_count, &mut Vec::new(), &mut visit);
+        best.map_or_else(String::new, |(_, best_cards)| best_cards)
+    }
+
+    /// Where a type of hand comes in `precedence`, the higher the better.
+    fn strength(&self, rank: HandRank) -> usize {
+        self.precedence.iter().position(|r| *r == rank).unwrap()
+    }
+
+    /// The best type of hand the cards can make.
+    pub fn hand_rank(&self, cards: &str) -> Result<HandRank, IoError> {
+        Ok(HandRank::from_grouping(&self.grouping(cards)?))
+    }
+
+    pub fn hand(&self, deal: &Deal) -> Result<Hand, IoError> {
+        let grouping = self.grouping(&deal.cards)?;
+        let rank = HandRank::from_grouping(&grouping);
+        Ok(Hand {
+            strength: self.strength(rank),
+            rank,
+            grouping,
+            cards: deal
+                .cards
+                .chars()
+                .map(|card| self.card_rank(card))
+                .collect(),
+            bid: deal.bid,
+        })
+    }
+}
+
+/// Which game to rank hands by.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Game {
+    /// Cards are written without suits, like `KTJJT`, and ties go to the first card that
+    /// differs in the order they were dealt.
+    CamelCards(Rules),
+
+    /// Cards are written with suits, like `KH TD JD JC TS`, and ties go to the highest groups
+    /// and then the highest kickers.
+    Poker,
+}
+
+impl Game {
+    pub fn hand(&self, cards: &str, bid: u32) -> Result<Hand, IoError> {
+        match self {
+            Self::CamelCards(rules) => {
+                if let Some(card) = cards.chars().find(|card| !CARDS.contains(*card)) {
+                    return Err(IoError::other(format!("unknown card '{card}'")));
+                }
+                let deal = Deal {
+                    cards: cards.to_owned(),
+                    bid,
+                };
+                rules.hand(&deal)
+            }
+            Self::Poker => poker::hand(&poker::parse_cards(cards)?, bid),
+        }
+    }
+}
+
+/// A deal ranked by a game's rules.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Hand {
+    /// Compared in order to break ties between hands of the same type.
+    cards: Vec<CardRank>,
+    bid: u32,
+    rank: HandRank,
+
+    /// How many of each card, largest count first, that gave the hand its rank.
+    grouping: Vec<usize>,
+
+    /// Where the type of hand comes in the game's precedence.
+    strength: usize,
+}
+
+impl Hand {
+    pub fn rank(&self) -> HandRank {
+        self.rank
+    }
+
+    pub fn grouping(&self) -> &[usize] {
+        &self.grouping
+    }
+}
+
+impl Ord for Hand {
+    /// The stronger type of hand wins, then the first card that differs.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength
+            .cmp(&other.strength)
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Every card, from weakest to strongest when jokers aren't wild.
+const CARDS: &str = "23456789TJQKA";
+
+fn parse(input: &str) -> Result<Vec<Deal>, ParseError> {
+    Line::all(Day07::DAY, input)
+        .map(|line| {
+            let text = line.text.trim();
+            let (cards, bid) = text
+                .split_once(' ')
+                .ok_or(line.missing_after(text, "bid"))?;
+            if let Some((index, card)) = cards.char_indices().find(|(_, c)| !CARDS.contains(*c)) {
+                let card = &cards[index..index + card.len_utf8()];
+                return Err(line.error(card, ParseErrorKind::Unexpected("card")));
+            }
+            Ok(Deal {
+                cards: cards.to_owned(),
+                bid: line.number(bid)?,
+            })
+        })
+        .collect()
+}
+
+/// Each bid multiplied by where its hand comes, weakest first.
+pub fn winnings(deals: &[Deal], rules: &Rules) -> Result<u32, IoError> {
+    let mut hands = deals
+        .iter()
+        .map(|deal| rules.hand(deal))
+        .collect::<Result<Vec<Hand>, IoError>>()?;
+    hands.sort();
+    Ok(hands
+        .iter()
+        .enumerate()
+        .map(|(rank, hand)| hand.bid * (rank + 1) as u32)
+        .sum())
+}
+
+/// How many there are of each card as dealt, largest count first.
+fn counts(cards: &str) -> Vec<usize> {
+    let mut counts: BTreeMap<char, usize> = BTreeMap::new();
+    for card in cards.chars() {
+        *counts.entry(card).or_default() += 1;
+    }
+    let mut counts: Vec<usize> = counts.into_values().collect();
+    counts.sort_by(|a, b| b.cmp(a));
+    counts
+}
+
+/// Call `visit` with every way to pick `count` of the candidates, repeats allowed, each in the
+/// order the candidates come.
+fn choices(
+    candidates: &[char],
+    count: usize,
+    chosen: &mut Vec<char>,
+    visit: &mut impl FnMut(&[char]),
+) {
+    if count == 0 {
+        return visit(chosen);
+    }
+    for (index, &candidate) in candidates.iter().enumerate() {
+        chosen.push(candidate);
+        choices(&candidates[index..], count - 1, chosen, visit);
+        chosen.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483";
+
+    #[test]
+    fn example_part_one() {
+        let deals = parse(EXAMPLE).unwrap();
+        let answer = Day07::part_one(&deals).unwrap();
+        assert_eq!(answer, 6440);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let deals = parse(EXAMPLE).unwrap();
+        let answer = Day07::part_two(&deals).unwrap();
+        assert_eq!(answer, 5905);
+    }
+
+    #[test]
+    fn wild_cards() {
+        let deuces = Rules::new(CARDS, "2").unwrap();
+        assert_eq!(deuces.hand_rank("2233K").unwrap(), HandRank::FourOfAKind);
+        assert_eq!(
+            Rules::standard().hand_rank("2233K").unwrap(),
+            HandRank::TwoPair
+        );
+
+        let jokers = Rules::jokers();
+        assert_eq!(jokers.hand_rank("JJJJJ").unwrap(), HandRank::FiveOfAKind);
+        assert_eq!(jokers.hand_rank("KTJJT").unwrap(), HandRank::FourOfAKind);
+        assert_eq!(jokers.hand_rank("2345J").unwrap(), HandRank::OnePair);
+        assert_eq!(jokers.hand_rank("2233J").unwrap(), HandRank::FullHouse);
+
+        let both = Rules::new("J23456789TQKA", "J2").unwrap();
+        assert_eq!(both.hand_rank("2J34K").unwrap(), HandRank::ThreeOfAKind);
+        assert_eq!(both.card_rank('J'), 1);
+    }
+
+    #[test]
+    fn any_hand_size() {
+        let rules = Rules::jokers();
+        assert_eq!(rules.grouping("KKK22").unwrap(), vec![3, 2]);
+        assert_eq!(rules.grouping("KJ").unwrap(), vec![2]);
+        assert_eq!(rules.hand_rank("KJ").unwrap(), HandRank::OnePair);
+        assert_eq!(rules.hand_rank("J").unwrap(), HandRank::HighCard);
+        assert_eq!(rules.grouping("KKKJ2233").unwrap(), vec![4, 2, 2]);
+        assert_eq!(rules.hand_rank("JJJJJJJ").unwrap(), HandRank::FiveOfAKind);
+
+        let error = rules.hand_rank("").unwrap_err();
+        assert_eq!(error.to_string(), "a hand needs at least one card");
+    }
+
+    #[test]
+    fn precedence() {
+        // Three of a kind beats a full house when it's harder to get.
+        let mut precedence = HandRank::CAMEL_CARDS;
+        precedence.swap(3, 4);
+        let rules = Rules::standard().with_precedence(&precedence).unwrap();
+        let deal = |cards: &str| Deal {
+            cards: cards.to_owned(),
+            bid: 1,
+        };
+        let hand = |rules: &Rules, cards| rules.hand(&deal(cards)).unwrap();
+        assert!(hand(&rules, "22234") > hand(&rules, "22233"));
+        let standard = Rules::standard();
+        assert!(hand(&standard, "22234") < hand(&standard, "22233"));
+
+        // Wild cards make the type the precedence favours, not the biggest group. With three of
+        // a kind above four of a kind, one joker makes a third 2 and the other the strongest
+        // card that doesn't pair.
+        precedence.swap(4, 5);
+        let rules = Rules::jokers().with_precedence(&precedence).unwrap();
+        assert_eq!(rules.hand_rank("223JJ").unwrap(), HandRank::ThreeOfAKind);
+        assert_eq!(rules.best_cards("223JJ"), "223A2");
+        assert_eq!(hand(&rules, "223JJ").grouping, vec![3, 1, 1]);
+        assert_eq!(Rules::jokers().best_cards("223JJ"), "22322");
+
+        assert!(Rules::standard().with_precedence(&precedence[1..]).is_err());
+        assert!(Rules::new("23456789TJQK", "").is_err());
+        assert!(Rules::new(CARDS, "X").is_err());
+    }
+
+    #[test]
+    fn parse_unknown_card() {
+        let error = parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.snippet, "X");
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("card"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Showing where each hand finished and why, for checking how wild cards were used.
+
+use std::fmt::{Display, Formatter, Write};
+use std::io::Error as IoError;
+
+use super::{Deal, Hand, HandRank, Rules};
+
+/// One hand after every hand has been ranked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Explanation {
+    pub cards: String,
+
+    /// The cards with the wild cards replaced by the ones they stood in for.
+    pub best_cards: String,
+
+    pub rank: HandRank,
+    pub bid: u32,
+
+    /// Where the hand finished, starting from one for the weakest.
+    pub position: usize,
+
+    /// The bid multiplied by the position.
+    pub winnings: u32,
+}
+
+impl Explanation {
+    /// Rank every deal under the rules, from weakest to strongest.
+    pub fn all(deals: &[Deal], rules: &Rules) -> Result<Vec<Explanation>, IoError> {
+        let mut hands = deals
+            .iter()
+            .map(|deal| Ok((rules.hand(deal)?, deal)))
+            .collect::<Result<Vec<(Hand, &Deal)>, IoError>>()?;
+        hands.sort_by(|(hand, _), (other, _)| hand.cmp(other));
+
+        let explanations = hands
+            .into_iter()
+            .enumerate()
+            .map(|(index, (hand, deal))| Explanation {
+                cards: deal.cards.clone(),
+                best_cards: rules.best_cards(&deal.cards),
+                rank: hand.rank,
+                bid: hand.bid,
+                position: index + 1,
+                winnings: hand.bid * (index + 1) as u32,
+            })
+            .collect();
+        Ok(explanations)
+    }
+
+    /// A header then one row per explanation.
+    pub fn to_csv(explanations: &[Explanation]) -> String {
+        let mut csv = String::from("position,cards,best cards,rank,bid,winnings\n");
+        for explanation in explanations {
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                explanation.position,
+                explanation.cards,
+                explanation.best_cards,
+                explanation.rank,
+                explanation.bid,
+                explanation.winnings
+            )
+            .unwrap();
+        }
+        csv
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>4}  {}", self.position, self.cards)?;
+        if self.best_cards != self.cards {
+            write!(f, " as {}", self.best_cards)?;
+        } else {
+            write!(f, "{:width$}", "", width = 4 + self.cards.len())?;
+        }
+        write!(
+            f,
+            "  {:<15}  {} x {} = {}",
+            self.rank, self.bid, self.position, self.winnings
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{parse, winnings};
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\nJJJJJ 1";
+
+    #[test]
+    fn explain_jokers() {
+        let deals = parse(EXAMPLE).unwrap();
+        let rules = Rules::jokers();
+        let explanations = Explanation::all(&deals, &rules).unwrap();
+        let total: u32 = explanations.iter().map(|e| e.winnings).sum();
+        assert_eq!(total, winnings(&deals, &rules).unwrap());
+
+        let text: Vec<String> = explanations.iter().map(Explanation::to_string).collect();
+        assert_eq!(
+            text,
+            vec![
+                "   1  32T3K           one pair         765 x 1 = 765",
+                "   2  KK677           two pair         28 x 2 = 56",
+                "   3  T55J5 as T5555  four of a kind   684 x 3 = 2052",
+                "   4  QQQJA as QQQQA  four of a kind   483 x 4 = 1932",
+                "   5  KTJJT as KTTTT  four of a kind   220 x 5 = 1100",
+                "   6  JJJJJ as AAAAA  five of a kind   1 x 6 = 6",
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_csv() {
+        let deals = parse("KTJJT 220").unwrap();
+        let explanations = Explanation::all(&deals, &Rules::standard()).unwrap();
+        assert_eq!(
+            Explanation::to_csv(&explanations),
+            "position,cards,best cards,rank,bid,winnings\n1,KTJJT,KTJJT,two pair,220,220\n"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Traditional poker, where cards have suits, straights and flushes count and ties are broken by
+//! kickers rather than by the order the cards were dealt.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+
+use super::{CardRank, Hand, HandRank, CARDS};
+
+/// Clubs, diamonds, hearts and spades.
+const SUITS: &str = "CDHS";
+
+/// A card from a standard deck, such as the ten of hearts.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Card {
+    pub value: char,
+    pub suit: char,
+}
+
+impl Card {
+    /// Twos are the weakest and aces the strongest.
+    fn rank(&self) -> CardRank {
+        CARDS.find(self.value).expect("known card") as CardRank + 1
+    }
+}
+
+impl Display for Card {
+    /// Value then suit, such as `TH`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.value, self.suit)
+    }
+}
+
+/// Cards separated by whitespace, each a value then a suit, such as `2H 3D 5S 9C KD`.
+pub fn parse_cards(text: &str) -> Result<Vec<Card>, IoError> {
+    text.split_whitespace()
+        .map(|word| match word.chars().collect::<Vec<char>>()[..] {
+            [value, suit] if CARDS.contains(value) && SUITS.contains(suit) => {
+                Ok(Card { value, suit })
+            }
+            _ => Err(IoError::other(format!("unknown card '{word}'"))),
+        })
+        .collect()
+}
+
+/// Rank five cards. Aces are high except in the straight from ace to five.
+pub fn hand(cards: &[Card], bid: u32) -> Result<Hand, IoError> {
+    if cards.len() != 5 {
+        return Err(IoError::other(format!(
+            "a poker hand has five cards, not {}",
+            cards.len()
+        )));
+    }
+    let mut sorted = cards.to_vec();
+    sorted.sort();
+    if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+        return Err(IoError::other(format!("{} is dealt twice", pair[0])));
+    }
+
+    let mut counts: BTreeMap<CardRank, usize> = BTreeMap::new();
+    for card in cards {
+        *counts.entry(card.rank()).or_default() += 1;
+    }
+
+    // The values that break ties, the largest groups first and then the highest.
+    let mut groups: Vec<(usize, CardRank)> = counts.into_iter().map(|(r, c)| (c, r)).collect();
+    groups.sort_by(|a, b| b.cmp(a));
+    let grouping: Vec<usize> = groups.iter().map(|(count, _)| *count).collect();
+    let mut tie_breaks: Vec<CardRank> = groups.iter().map(|(_, rank)| *rank).collect();
+
+    let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let straight_high = match tie_breaks[..] {
+        // Ace, five, four, three, two.
+        [13, 4, 3, 2, 1] => Some(4),
+        [high, .., low] if tie_breaks.len() == 5 && high - low == 4 => Some(high),
+        _ => None,
+    };
+    if let Some(high) = straight_high {
+        tie_breaks = vec![high];
+    }
+
+    let rank = match (straight_high.is_some(), flush) {
+        (true, true) => HandRank::StraightFlush,
+        (false, true) => HandRank::Flush,
+        (true, false) => HandRank::Straight,
+        (false, false) => HandRank::from_grouping(&grouping),
+    };
+    Ok(Hand {
+        cards: tie_breaks,
+        bid,
+        rank,
+        grouping,
+        strength: HandRank::POKER.iter().position(|r| *r == rank).unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Game, Rules};
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        Game::Poker.hand(cards, 1).unwrap()
+    }
+
+    #[test]
+    fn poker_ranks() {
+        assert_eq!(hand("2H 2D 4C 4D 4S").rank(), HandRank::FullHouse);
+        assert_eq!(hand("3D 6D 7D TD QD").rank(), HandRank::Flush);
+        assert_eq!(hand("AH 2D 3C 4D 5S").rank(), HandRank::Straight);
+        assert_eq!(hand("9C TC JC QC KC").rank(), HandRank::StraightFlush);
+        assert_eq!(hand("QH KD AC 2D 3S").rank(), HandRank::HighCard);
+    }
+
+    #[test]
+    fn kickers_break_ties() {
+        // Pair of eights beats a pair of fives whatever the other cards.
+        assert!(hand("5H 5C 6S 7S KD") < hand("2C 3S 8S 8D TD"));
+        assert!(hand("5D 8C 9S JS AC") > hand("2C 5C 7D 8S QH"));
+        assert!(hand("2D 9C AS AH AC") < hand("3D 6D 7D TD QD"));
+        assert!(hand("4D 6S 9H QH QC") > hand("3D 6D 7H QD QS"));
+        assert!(hand("2H 2D 4C 4D 4S") > hand("3C 3D 3S 9S 9D"));
+        assert!(hand("AH 2D 3C 4D 5S") < hand("2H 3D 4C 5D 6S"));
+
+        assert!(hand("2H AD 2C 3S 4D") > hand("2S KD 2D QS JD"));
+
+        // Camel Cards compares the first card instead of the pairs.
+        let camel = Game::CamelCards(Rules::standard());
+        let camel_hand = |cards| camel.hand(cards, 1).unwrap();
+        assert!(camel_hand("K2234") > camel_hand("QAA34"));
+        assert!(hand("KH 2D 2C 3S 4D") < hand("QH AD AC 3S 4D"));
+    }
+
+    #[test]
+    fn bad_hands() {
+        let error = |cards| Game::Poker.hand(cards, 1).unwrap_err().to_string();
+        assert_eq!(error("2H 3D 4C 5D"), "a poker hand has five cards, not 4");
+        assert_eq!(error("2H 3D 4C 5D 2H"), "2H is dealt twice");
+        assert_eq!(error("2H 3D 4C 5D 1H"), "unknown card '1H'");
+    }
+}
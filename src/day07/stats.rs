@@ -0,0 +1,103 @@
+//! How many hands there are of each type and how jokers change them.
+
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+
+use super::{Deal, HandRank, Rules};
+
+/// Every hand classified under the rules of both parts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Distribution {
+    /// How many hands have each type in part one then in part two, indexed by the types'
+    /// positions in [`HandRank::CAMEL_CARDS`].
+    transitions: [[usize; 7]; 7],
+}
+
+impl Distribution {
+    pub fn new(deals: &[Deal]) -> Result<Self, IoError> {
+        let (part_one, part_two) = (Rules::standard(), Rules::jokers());
+        let mut transitions = [[0; 7]; 7];
+        for deal in deals {
+            let from = index(part_one.hand_rank(&deal.cards)?);
+            let to = index(part_two.hand_rank(&deal.cards)?);
+            transitions[from][to] += 1;
+        }
+        Ok(Self { transitions })
+    }
+
+    /// How many hands have the type in part one.
+    pub fn part_one(&self, rank: HandRank) -> usize {
+        self.transitions[index(rank)].iter().sum()
+    }
+
+    /// How many hands have the type in part two.
+    pub fn part_two(&self, rank: HandRank) -> usize {
+        let to = index(rank);
+        self.transitions.iter().map(|row| row[to]).sum()
+    }
+
+    /// How many hands have one type in part one and the other in part two.
+    pub fn transition(&self, from: HandRank, to: HandRank) -> usize {
+        self.transitions[index(from)][index(to)]
+    }
+}
+
+fn index(rank: HandRank) -> usize {
+    HandRank::CAMEL_CARDS
+        .iter()
+        .position(|r| *r == rank)
+        .expect("Camel Cards type")
+}
+
+impl Display for Distribution {
+    /// A histogram of the types in each part, then a matrix with a row for each type in part
+    /// one and a column for each type in part two.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        const WIDTH: usize = 15;
+        writeln!(f, "{:WIDTH$}  Part one  Part two", "Type")?;
+        for rank in HandRank::CAMEL_CARDS {
+            let (one, two) = (self.part_one(rank), self.part_two(rank));
+            writeln!(f, "{rank:WIDTH$}  {one:>8}  {two:>8}")?;
+        }
+
+        write!(f, "\n{:WIDTH$}", "One \\ two")?;
+        for rank in HandRank::CAMEL_CARDS {
+            write!(f, "  {rank}")?;
+        }
+        for from in HandRank::CAMEL_CARDS {
+            write!(f, "\n{from:WIDTH$}")?;
+            for to in HandRank::CAMEL_CARDS {
+                let width = to.to_string().len();
+                write!(f, "  {:>width$}", self.transition(from, to))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::*;
+
+    const EXAMPLE: &str = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+
+    #[test]
+    fn example_distribution() {
+        let distribution = Distribution::new(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(distribution.part_one(HandRank::TwoPair), 2);
+        assert_eq!(distribution.part_two(HandRank::TwoPair), 1);
+        assert_eq!(distribution.part_two(HandRank::FourOfAKind), 3);
+        let transition = distribution.transition(HandRank::ThreeOfAKind, HandRank::FourOfAKind);
+        assert_eq!(transition, 2);
+
+        let text = distribution.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "Type             Part one  Part two");
+        assert_eq!(lines[3], "two pair                2         1");
+        assert_eq!(
+            lines[12],
+            "two pair                 0         0         1                0           0               1               0"
+        );
+    }
+}
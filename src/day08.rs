@@ -0,0 +1,264 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::io::Error as IoError;
+
+use crate::error::{missing_line, Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+mod cycles;
+mod dot;
+mod graph;
+mod walker;
+
+pub use cycles::{first_common_end, has_common_end, Cycle};
+pub use graph::{Graph, NodeIndex, Walk};
+pub use walker::{Nodes, Outcome, Walker};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = (Instructions, Vec<Node>);
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one((instructions, nodes): &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let graph = Graph::new(nodes);
+        let (start, goal) = (Nodes::Id("AAA".to_owned()), Nodes::Id("ZZZ".to_owned()));
+        match graph.walker(instructions, &start, &goal).steps() {
+            Outcome::Reached(steps) => Ok(steps),
+            Outcome::NoStarts => Err(IoError::other("no node 'AAA'").into()),
+            Outcome::NoGoals => Err(IoError::other("no node 'ZZZ'").into()),
+            Outcome::NoInstructions => Err(IoError::other("no instructions").into()),
+            Outcome::Never | Outcome::PastMaxSteps => {
+                Err(IoError::other("'AAA' never reaches 'ZZZ'").into())
+            }
+        }
+    }
+
+    fn part_two((instructions, nodes): &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        let graph = Graph::new(nodes);
+
+        // Each path can pass several nodes ending in 'Z' and take a while to start repeating.
+        let (starts, goals) = (Nodes::Suffix("A".to_owned()), Nodes::Suffix("Z".to_owned()));
+        match graph.walker(instructions, &starts, &goals).steps() {
+            Outcome::Reached(steps) => Ok(steps),
+            Outcome::NoStarts => Err(IoError::other("no nodes ending in 'A'").into()),
+            Outcome::NoGoals => Err(IoError::other("no nodes ending in 'Z'").into()),
+            Outcome::NoInstructions => Err(IoError::other("no instructions").into()),
+            Outcome::Never | Outcome::PastMaxSteps => {
+                Err(IoError::other("the paths never all end at once").into())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Node {
+    id: NodeId,
+    left: String,
+    right: String,
+}
+
+type NodeId = String;
+pub type Instructions = String;
+
+fn parse(input: &str) -> Result<(Instructions, Vec<Node>), ParseError> {
+    let mut lines = Line::all(Day08::DAY, input);
+    let missing = |what| missing_line(Day08::DAY, input, what);
+
+    let instructions_line = lines.next().ok_or_else(|| missing("instructions"))?;
+    let instructions = instructions_line.text.trim();
+    if instructions.is_empty() {
+        return Err(instructions_line.missing_after(instructions_line.text, "instructions"));
+    }
+    if let Some((index, instruction)) = instructions
+        .char_indices()
+        .find(|(_, c)| *c != 'L' && *c != 'R')
+    {
+        let instruction = &instructions[index..index + instruction.len_utf8()];
+        return Err(instructions_line.error(instruction, ParseErrorKind::Unexpected("instruction")));
+    }
+    let blank_line = lines.next().ok_or_else(|| missing("blank line"))?;
+    if !blank_line.text.trim().is_empty() {
+        return Err(blank_line.error(blank_line.text, ParseErrorKind::Missing("blank line")));
+    }
+
+    // Every line looks like "AAA = (BBB, CCC)", though ids can be any length and spacing can vary.
+    let mut defined = BTreeSet::new();
+    let mut references = Vec::new();
+    let mut nodes = Vec::new();
+    for line in lines.filter(|line| !line.text.trim().is_empty()) {
+        let (id_text, rest) = line
+            .text
+            .split_once('=')
+            .ok_or_else(|| line.missing_after(line.text, "'='"))?;
+        let rest = rest.trim();
+        let inside = rest
+            .strip_prefix('(')
+            .ok_or_else(|| line.error(rest, ParseErrorKind::Missing("'('")))?;
+        let (left_text, right_text) = inside
+            .split_once(',')
+            .ok_or_else(|| line.missing_after(inside, "','"))?;
+        let right_text = right_text
+            .strip_suffix(')')
+            .ok_or_else(|| line.missing_after(right_text, "')'"))?;
+
+        let (id, left, right) = (
+            node_id(&line, id_text)?,
+            node_id(&line, left_text)?,
+            node_id(&line, right_text)?,
+        );
+        if !defined.insert(id) {
+            return Err(line.error(id, ParseErrorKind::Unexpected("second definition of node")));
+        }
+        references.extend([(line, left), (line, right)]);
+        nodes.push(Node {
+            id: id.to_owned(),
+            left: left.to_owned(),
+            right: right.to_owned(),
+        });
+    }
+    if let Some((line, id)) = references.iter().find(|(_, id)| !defined.contains(id)) {
+        return Err(line.error(id, ParseErrorKind::Unexpected("undefined node")));
+    }
+    Ok((instructions.to_owned(), nodes))
+}
+
+/// An id of letters and digits with any whitespace around it.
+fn node_id<'a>(line: &Line, text: &'a str) -> Result<&'a str, ParseError> {
+    let id = text.trim();
+    if id.is_empty() {
+        Err(line.missing_after(text, "node id"))
+    } else if !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Err(line.error(id, ParseErrorKind::Unexpected("node id")))
+    } else {
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "RL
+
+        AAA = (BBB, CCC)
+        BBB = (DDD, EEE)
+        CCC = (ZZZ, GGG)
+        DDD = (DDD, DDD)
+        EEE = (EEE, EEE)
+        GGG = (GGG, GGG)
+        ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn example1_part_one() {
+        let network = parse(EXAMPLE1).unwrap();
+        let answer = Day08::part_one(&network).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    const EXAMPLE2: &str = " LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn example2_part_one() {
+        let network = parse(EXAMPLE2).unwrap();
+        let answer = Day08::part_one(&network).unwrap();
+        assert_eq!(answer, 6);
+    }
+
+    pub(super) const EXAMPLE3: &str = "LR
+
+        11A = (11B, XXX)
+        11B = (XXX, 11Z)
+        11Z = (11B, XXX)
+        22A = (22B, XXX)
+        22B = (22C, 22C)
+        22C = (22Z, 22Z)
+        22Z = (22B, 22B)
+        XXX = (XXX, XXX)";
+
+    #[test]
+    fn example3_part_two() {
+        let network = parse(EXAMPLE3).unwrap();
+        let answer = Day08::part_two(&network).unwrap();
+        assert_eq!(answer, 6);
+    }
+
+    #[test]
+    fn parse_unknown_instruction() {
+        let error = parse("LRX\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("instruction"));
+    }
+
+    #[test]
+    fn missing_start_or_goal() {
+        let network = parse("L\n\nAAB = (AAB, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let error = Day08::part_one(&network).unwrap_err();
+        assert_eq!(error.to_string(), "no node 'AAA'");
+        let network = parse("L\n\nAAA = (AAA, AAA)").unwrap();
+        let error = Day08::part_one(&network).unwrap_err();
+        assert_eq!(error.to_string(), "no node 'ZZZ'");
+        let network = parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let error = Day08::part_one(&network).unwrap_err();
+        assert_eq!(error.to_string(), "'AAA' never reaches 'ZZZ'");
+    }
+
+    #[test]
+    fn parse_no_instructions() {
+        let error = parse("\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.kind, ParseErrorKind::Missing("instructions"));
+    }
+
+    #[test]
+    fn parse_short_node() {
+        let error = parse("LR\n\nAAA = (AAA, AAA)\nBBB = (AAA").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 11));
+        assert_eq!(error.kind, ParseErrorKind::Missing("','"));
+    }
+
+    #[test]
+    fn parse_any_length_and_spacing() {
+        let (_, nodes) =
+            parse("L\n\nSTART=(A1 ,  END)\n\n  A1 = ( END,START )\nEND = (END, END)").unwrap();
+        let ids: Vec<(&str, &str, &str)> = nodes
+            .iter()
+            .map(|node| (&node.id[..], &node.left[..], &node.right[..]))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("START", "A1", "END"),
+                ("A1", "END", "START"),
+                ("END", "END", "END")
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_duplicate_node() {
+        let error = parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Unexpected("second definition of node")
+        );
+    }
+
+    #[test]
+    fn parse_undefined_node() {
+        let error = parse("L\n\nAAA = (AAA, BBB)").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 13));
+        assert_eq!(error.snippet, "BBB");
+        assert_eq!(error.kind, ParseErrorKind::Unexpected("undefined node"));
+    }
+}
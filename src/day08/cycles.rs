@@ -0,0 +1,278 @@
+//! Where each ghost's walk starts repeating and when it's on an end node, so the ghosts can be
+//! lined up without assuming each walk reaches a single end at a regular interval.
+
+use super::{Graph, NodeIndex};
+
+/// A walk is in the same state when it's on the same node about to follow the same instruction,
+/// and from then on it repeats.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// Steps before the walk first reaches the state it comes back to.
+    pub prefix: u64,
+
+    /// Steps before the walk comes back to that state.
+    pub length: u64,
+
+    /// Every step before the walk first comes back where it's on an end node, in order. Those
+    /// from `prefix` on happen again every `length` steps.
+    pub ends: Vec<u64>,
+}
+
+impl Cycle {
+    /// Walk from `start` until a state repeats, noting the steps that land on an end node. There
+    /// must be at least one instruction.
+    pub fn find(
+        graph: &Graph,
+        instructions: &str,
+        start: NodeIndex,
+        is_end: impl Fn(NodeIndex) -> bool,
+    ) -> Cycle {
+        let instructions = instructions.as_bytes();
+        // When the walk was first in each state, by node then instruction.
+        let mut seen: Vec<Option<u64>> = vec![None; graph.len() * instructions.len()];
+        let mut ends = Vec::new();
+        let mut node = start;
+        let mut step = 0;
+        loop {
+            let index = (step % instructions.len() as u64) as usize;
+            let state = node as usize * instructions.len() + index;
+            if let Some(prefix) = seen[state] {
+                return Cycle {
+                    prefix,
+                    length: step - prefix,
+                    ends,
+                };
+            }
+            seen[state] = Some(step);
+            if is_end(node) {
+                ends.push(step);
+            }
+            node = graph.next(node, instructions[index]);
+            step += 1;
+        }
+    }
+
+    /// Whether the walk is on an end node after `step` steps.
+    pub fn is_end(&self, step: u64) -> bool {
+        let step = if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.length
+        };
+        self.ends.binary_search(&step).is_ok()
+    }
+
+    /// The ends that keep coming back.
+    fn repeating_ends(&self) -> &[u64] {
+        let first = self.ends.partition_point(|&end| end < self.prefix);
+        &self.ends[first..]
+    }
+}
+
+/// The first step after the start, up to `max_steps`, when every walk is on an end node at once,
+/// if there is one.
+pub fn first_common_end(cycles: &[Cycle], max_steps: u64) -> Option<u64> {
+    let early = early_common_end(cycles, max_steps);
+    if early.is_some() {
+        return early;
+    }
+
+    // After that each repeating end is a congruence, so try the ways to pick one per walk.
+    let settled = settled(cycles)?;
+    let mut best = None;
+    search(cycles, (0, 1), u128::from(settled), max_steps, &mut best);
+    best
+}
+
+/// The first step from which every walk is repeating, or `None` without any walks.
+fn settled(cycles: &[Cycle]) -> Option<u64> {
+    Some(cycles.iter().map(|cycle| cycle.prefix).max()?.max(1))
+}
+
+/// Before every walk has started repeating the ends have to be checked one by one.
+fn early_common_end(cycles: &[Cycle], max_steps: u64) -> Option<u64> {
+    let settled = settled(cycles)?;
+    let all_ends = |step| cycles.iter().all(|cycle| cycle.is_end(step));
+    cycles
+        .iter()
+        .flat_map(|cycle| cycle.ends.iter().copied())
+        .filter(|&end| end > 0 && end < settled && end <= max_steps && all_ends(end))
+        .min()
+}
+
+/// Whether the walks are ever all on an end node at once after the start, however late.
+pub fn has_common_end(cycles: &[Cycle]) -> bool {
+    /// Whether a repeating end can be picked from each of the remaining cycles to go with the
+    /// congruence so far.
+    fn fits(cycles: &[Cycle], congruence: (u128, u128)) -> bool {
+        let Some((cycle, rest)) = cycles.split_first() else {
+            return true;
+        };
+        cycle.repeating_ends().iter().any(|&end| {
+            let end = (u128::from(end % cycle.length), u128::from(cycle.length));
+            combine(congruence, end).is_some_and(|congruence| fits(rest, congruence))
+        })
+    }
+    !cycles.is_empty() && (early_common_end(cycles, u64::MAX).is_some() || fits(cycles, (0, 1)))
+}
+
+/// Pick a repeating end from each of the remaining cycles to go with the congruence so far,
+/// keeping the smallest step that fits them all. Gives up on a congruence as soon as its first
+/// step is past `max_steps` or no better than the best so far, since more picks only make the
+/// first step later.
+fn search(
+    cycles: &[Cycle],
+    congruence: (u128, u128),
+    settled: u128,
+    max_steps: u64,
+    best: &mut Option<u64>,
+) {
+    let (remainder, modulus) = congruence;
+    let step = if remainder >= settled {
+        remainder
+    } else {
+        remainder + (settled - remainder).div_ceil(modulus) * modulus
+    };
+    let limit = best.map_or(u128::from(max_steps), |best| u128::from(best) - 1);
+    if step > limit {
+        return;
+    }
+    let Some((cycle, rest)) = cycles.split_first() else {
+        *best = Some(step as u64);
+        return;
+    };
+    for &end in cycle.repeating_ends() {
+        let end = (u128::from(end % cycle.length), u128::from(cycle.length));
+        if let Some(congruence) = combine(congruence, end) {
+            search(rest, congruence, settled, max_steps, best);
+        }
+    }
+}
+
+/// The steps that are `a.0` more than a multiple of `a.1` and `b.0` more than a multiple of
+/// `b.1`, as a remainder and modulus. The moduli don't have to be coprime.
+fn combine(a: (u128, u128), b: (u128, u128)) -> Option<(u128, u128)> {
+    let (divisor, inverse) = extended_gcd(a.1 as i128, b.1 as i128);
+    let divisor = divisor as u128;
+    let difference = (b.0 as i128 - a.0 as i128).rem_euclid(b.1 as i128) as u128;
+    if !difference.is_multiple_of(divisor) {
+        return None;
+    }
+    let step = b.1 / divisor;
+    let multiple =
+        (difference / divisor % step) * (inverse.rem_euclid(step as i128) as u128) % step;
+    let modulus = a.1 * step;
+    Some(((a.0 + a.1 * multiple) % modulus, modulus))
+}
+
+/// The greatest common divisor of `a` and `b`, and a number that `a` can be multiplied by to
+/// leave that divisor modulo `b`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128) {
+    // Keeps `a * x` congruent to the remainders modulo `b` as they shrink.
+    let (mut remainder, mut next_remainder) = (a, b);
+    let (mut x, mut next_x) = (1, 0);
+    while next_remainder != 0 {
+        let quotient = remainder / next_remainder;
+        (remainder, next_remainder) = (next_remainder, remainder - quotient * next_remainder);
+        (x, next_x) = (next_x, x - quotient * next_x);
+    }
+    (remainder, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::super::tests::EXAMPLE3;
+    use super::*;
+
+    fn cycles(input: &str) -> Vec<Cycle> {
+        let (instructions, nodes) = parse(input).unwrap();
+        let graph = Graph::new(&nodes);
+        graph
+            .starts()
+            .iter()
+            .map(|&start| Cycle::find(&graph, &instructions, start, |node| graph.is_end(node)))
+            .collect()
+    }
+
+    #[test]
+    fn example_cycles() {
+        let cycles = cycles(EXAMPLE3);
+        let expected = vec![
+            Cycle {
+                prefix: 1,
+                length: 2,
+                ends: vec![2],
+            },
+            Cycle {
+                prefix: 1,
+                length: 6,
+                ends: vec![3, 6],
+            },
+        ];
+        assert_eq!(cycles, expected);
+        assert_eq!(first_common_end(&cycles, u64::MAX), Some(6));
+        assert_eq!(first_common_end(&cycles, 6), Some(6));
+        assert_eq!(first_common_end(&cycles, 5), None);
+        assert!(has_common_end(&cycles));
+    }
+
+    #[test]
+    fn lengths_to_first_end_are_not_enough() {
+        // The first path ends after 1, 4, 7... steps and the second after 2, 4, 6... so the
+        // least common multiple of the first ends, 2, is wrong.
+        let cycles = cycles(
+            "L
+
+            11A = (11Z, 11Z)
+            11Z = (11B, 11B)
+            11B = (11C, 11C)
+            11C = (11Z, 11Z)
+            22A = (22B, 22B)
+            22B = (22Z, 22Z)
+            22Z = (22B, 22B)",
+        );
+        assert_eq!(cycles[0].ends, vec![1]);
+        assert_eq!(first_common_end(&cycles, u64::MAX), Some(4));
+    }
+
+    #[test]
+    fn ends_before_repeating() {
+        // The first path only ends once, before it starts going round 11B and 11C.
+        let cycles = cycles(
+            "L
+
+            11A = (11Z, 11Z)
+            11Z = (11B, 11B)
+            11B = (11C, 11C)
+            11C = (11B, 11B)
+            22A = (22Z, 22Z)
+            22Z = (22Z, 22Z)",
+        );
+        assert_eq!(cycles[0].prefix, 2);
+        assert_eq!(first_common_end(&cycles, u64::MAX), Some(1));
+        assert_eq!(first_common_end(&cycles[..1], u64::MAX), Some(1));
+    }
+
+    #[test]
+    fn never_end_together() {
+        // One path ends on odd steps and the other on even steps.
+        let cycles = cycles(
+            "L
+
+            11A = (11Z, 11Z)
+            11Z = (11A, 11A)
+            22A = (22B, 22B)
+            22B = (22Z, 22Z)
+            22Z = (22B, 22B)",
+        );
+        assert_eq!(first_common_end(&cycles, u64::MAX), None);
+        assert!(!has_common_end(&cycles));
+    }
+
+    #[test]
+    fn moduli_share_factors() {
+        assert_eq!(combine((2, 4), (4, 6)), Some((10, 12)));
+        assert_eq!(combine((1, 4), (2, 6)), None);
+    }
+}
@@ -0,0 +1,104 @@
+//! Writing the network for Graphviz, to see why a walk goes round the nodes it does.
+
+use std::fmt::Write;
+
+use super::{Cycle, Graph, NodeIndex};
+
+impl Graph {
+    /// Every node a walk from `start` is on before it starts repeating, including `start`. There
+    /// must be at least one instruction.
+    pub fn visited(&self, instructions: &str, start: NodeIndex) -> Vec<NodeIndex> {
+        let cycle = Cycle::find(self, instructions, start, |_| false);
+        let steps = (cycle.prefix + cycle.length - 1) as usize;
+        let mut visited = vec![false; self.len()];
+        visited[start as usize] = true;
+        for node in self.walk(instructions, start).take(steps) {
+            visited[node as usize] = true;
+        }
+        (0..self.len() as NodeIndex)
+            .filter(|&index| visited[index as usize])
+            .collect()
+    }
+
+    /// A DOT digraph with an edge for each instruction, filling the start nodes green and the end
+    /// nodes red. The `visited` nodes get a thick blue outline.
+    pub fn to_dot(&self, visited: &[NodeIndex]) -> String {
+        let mut outlined = vec![false; self.len()];
+        for &index in visited {
+            outlined[index as usize] = true;
+        }
+
+        let mut dot = String::from("digraph network {\n");
+        for index in 0..self.len() as NodeIndex {
+            let mut attributes = Vec::new();
+            if self.starts().contains(&index) {
+                attributes.push("style=filled, fillcolor=palegreen");
+            } else if self.is_end(index) {
+                attributes.push("style=filled, fillcolor=lightcoral");
+            }
+            if outlined[index as usize] {
+                attributes.push("color=blue, penwidth=3");
+            }
+            if !attributes.is_empty() {
+                writeln!(
+                    dot,
+                    "    \"{}\" [{}];",
+                    self.id(index),
+                    attributes.join(", ")
+                )
+                .unwrap();
+            }
+        }
+        for index in 0..self.len() as NodeIndex {
+            let (left, right) = (self.next(index, b'L'), self.next(index, b'R'));
+            let id = self.id(index);
+            if left == right {
+                writeln!(dot, "    \"{id}\" -> \"{}\" [label=\"LR\"];", self.id(left)).unwrap();
+            } else {
+                writeln!(dot, "    \"{id}\" -> \"{}\" [label=\"L\"];", self.id(left)).unwrap();
+                writeln!(dot, "    \"{id}\" -> \"{}\" [label=\"R\"];", self.id(right)).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::super::tests::EXAMPLE3;
+    use super::*;
+
+    #[test]
+    fn example_dot() {
+        let (instructions, nodes) = parse(EXAMPLE3).unwrap();
+        let graph = Graph::new(&nodes);
+        let visited = graph.visited(&instructions, 3);
+        assert_eq!(visited, vec![3, 4, 5, 6]);
+        assert_eq!(
+            graph.to_dot(&visited),
+            "digraph network {
+    \"11A\" [style=filled, fillcolor=palegreen];
+    \"11Z\" [style=filled, fillcolor=lightcoral];
+    \"22A\" [style=filled, fillcolor=palegreen, color=blue, penwidth=3];
+    \"22B\" [color=blue, penwidth=3];
+    \"22C\" [color=blue, penwidth=3];
+    \"22Z\" [style=filled, fillcolor=lightcoral, color=blue, penwidth=3];
+    \"11A\" -> \"11B\" [label=\"L\"];
+    \"11A\" -> \"XXX\" [label=\"R\"];
+    \"11B\" -> \"XXX\" [label=\"L\"];
+    \"11B\" -> \"11Z\" [label=\"R\"];
+    \"11Z\" -> \"11B\" [label=\"L\"];
+    \"11Z\" -> \"XXX\" [label=\"R\"];
+    \"22A\" -> \"22B\" [label=\"L\"];
+    \"22A\" -> \"XXX\" [label=\"R\"];
+    \"22B\" -> \"22C\" [label=\"LR\"];
+    \"22C\" -> \"22Z\" [label=\"LR\"];
+    \"22Z\" -> \"22B\" [label=\"LR\"];
+    \"XXX\" -> \"XXX\" [label=\"LR\"];
+}
+"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! The network with every node numbered, so walking it follows indices instead of looking up
+//! ids and long walks don't allocate.
+
+use std::collections::HashMap;
+use std::iter::Cycle as Repeat;
+use std::slice::Iter;
+
+use super::{Node, NodeId};
+
+/// A node's position in the order the network lists them.
+pub type NodeIndex = u32;
+
+/// Where each node leads, by index.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Graph {
+    ids: Vec<NodeId>,
+    indices: HashMap<NodeId, NodeIndex>,
+    left: Vec<NodeIndex>,
+    right: Vec<NodeIndex>,
+
+    /// Nodes ending in 'A', in order.
+    starts: Vec<NodeIndex>,
+
+    /// Nodes ending in 'Z', in order.
+    ends: Vec<NodeIndex>,
+    is_end: Vec<bool>,
+}
+
+impl Graph {
+    /// Number the nodes. Every node they lead to must be among them, which parsing checks.
+    pub fn new(nodes: &[Node]) -> Graph {
+        let indices: HashMap<NodeId, NodeIndex> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id.clone(), index as NodeIndex))
+            .collect();
+        let index = |id: &String| *indices.get(id).expect("defined node");
+        let ids: Vec<NodeId> = nodes.iter().map(|node| node.id.clone()).collect();
+        let is_end: Vec<bool> = ids.iter().map(|id| id.ends_with('Z')).collect();
+        Graph {
+            left: nodes.iter().map(|node| index(&node.left)).collect(),
+            right: nodes.iter().map(|node| index(&node.right)).collect(),
+            starts: (0..ids.len())
+                .filter(|&index| ids[index].ends_with('A'))
+                .map(|index| index as NodeIndex)
+                .collect(),
+            ends: (0..ids.len())
+                .filter(|&index| is_end[index])
+                .map(|index| index as NodeIndex)
+                .collect(),
+            ids,
+            indices,
+            is_end,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn index(&self, id: &str) -> Option<NodeIndex> {
+        self.indices.get(id).copied()
+    }
+
+    pub fn id(&self, index: NodeIndex) -> &str {
+        &self.ids[index as usize]
+    }
+
+    /// Every node whose id matches, in order.
+    pub fn select(&self, predicate: impl Fn(&str) -> bool) -> Vec<NodeIndex> {
+        (0..self.len() as NodeIndex)
+            .filter(|&index| predicate(self.id(index)))
+            .collect()
+    }
+
+    /// Nodes ending in 'A'.
+    pub fn starts(&self) -> &[NodeIndex] {
+        &self.starts
+    }
+
+    /// Nodes ending in 'Z'.
+    pub fn ends(&self) -> &[NodeIndex] {
+        &self.ends
+    }
+
+    pub fn is_end(&self, index: NodeIndex) -> bool {
+        self.is_end[index as usize]
+    }
+
+    /// The node an instruction leads to. Anything other than 'L' goes right.
+    pub fn next(&self, index: NodeIndex, instruction: u8) -> NodeIndex {
+        match instruction {
+            b'L' => self.left[index as usize],
+            _ => self.right[index as usize],
+        }
+    }
+
+    /// The nodes reached after each step from `start`, repeating the instructions forever.
+    pub fn walk<'a>(&'a self, instructions: &'a str, start: NodeIndex) -> Walk<'a> {
+        Walk {
+            graph: self,
+            instructions: instructions.as_bytes().iter().cycle(),
+            node: start,
+        }
+    }
+}
+
+/// Follows the instructions around a graph without allocating.
+#[derive(Clone, Debug)]
+pub struct Walk<'a> {
+    graph: &'a Graph,
+    instructions: Repeat<Iter<'a, u8>>,
+    node: NodeIndex,
+}
+
+impl Iterator for Walk<'_> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<NodeIndex> {
+        let instruction = self.instructions.next()?;
+        self.node = self.graph.next(self.node, *instruction);
+        Some(self.node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::super::tests::EXAMPLE3;
+    use super::*;
+
+    #[test]
+    fn example_graph() {
+        let (instructions, nodes) = parse(EXAMPLE3).unwrap();
+        let graph = Graph::new(&nodes);
+        assert_eq!(graph.len(), 8);
+        assert_eq!(graph.starts(), &[0, 3]);
+        assert_eq!(graph.ends(), &[2, 6]);
+        assert_eq!(graph.select(|id| id.starts_with('2')), vec![3, 4, 5, 6]);
+        assert_eq!(graph.index("22C"), Some(5));
+        assert_eq!(graph.index("33A"), None);
+
+        let walk: Vec<&str> = graph
+            .walk(&instructions, 3)
+            .take(4)
+            .map(|index| graph.id(index))
+            .collect();
+        assert_eq!(walk, vec!["22B", "22C", "22Z", "22B"]);
+    }
+}
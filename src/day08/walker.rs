@@ -0,0 +1,251 @@
+//! Walking from any nodes to any others, for variants of the puzzle that don't go from 'AAA' to
+//! 'ZZZ' or from the nodes ending in 'A' to those ending in 'Z'.
+
+use std::collections::BTreeSet;
+
+use super::{first_common_end, has_common_end, Cycle, Graph, NodeId, NodeIndex};
+
+/// Which nodes to start or finish on, by id.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Nodes {
+    /// The node with exactly this id.
+    Id(NodeId),
+
+    /// Nodes whose ids end with this.
+    Suffix(String),
+
+    /// Nodes whose whole id matches, where '.' is any one character and '*' any number of them.
+    Pattern(String),
+
+    /// Nodes with any of these ids.
+    Set(BTreeSet<NodeId>),
+}
+
+impl Nodes {
+    pub fn contains(&self, id: &str) -> bool {
+        match self {
+            Nodes::Id(other) => id == other,
+            Nodes::Suffix(suffix) => id.ends_with(suffix.as_str()),
+            Nodes::Pattern(pattern) => matches(pattern.as_bytes(), id.as_bytes()),
+            Nodes::Set(ids) => ids.contains(id),
+        }
+    }
+}
+
+/// Whether the whole id matches, trying every length for each '*'.
+fn matches(pattern: &[u8], id: &[u8]) -> bool {
+    match (pattern.split_first(), id.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            matches(rest, id) || (!id.is_empty() && matches(pattern, &id[1..]))
+        }
+        (Some((&expected, rest)), Some((&found, id_rest))) => {
+            (expected == b'.' || expected == found) && matches(rest, id_rest)
+        }
+        _ => false,
+    }
+}
+
+/// How a walk turned out.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// Every walk was on a goal at once after this many steps.
+    Reached(u64),
+
+    /// The walks go round without ever all being on a goal at once.
+    Never,
+
+    /// The walks are all on a goal at once, but not within the step cap.
+    PastMaxSteps,
+
+    /// No nodes matched the start nodes.
+    NoStarts,
+
+    /// No nodes matched the goals.
+    NoGoals,
+
+    /// There are no instructions to follow.
+    NoInstructions,
+}
+
+/// Walks from every start node at once until they're all on a goal node.
+#[derive(Clone, Debug)]
+pub struct Walker<'a> {
+    graph: &'a Graph,
+    instructions: &'a str,
+    starts: Vec<NodeIndex>,
+    is_goal: Vec<bool>,
+    max_steps: u64,
+}
+
+impl Graph {
+    /// Walk from the nodes in `starts` towards those in `goals` with no step cap.
+    pub fn walker<'a>(
+        &'a self,
+        instructions: &'a str,
+        starts: &Nodes,
+        goals: &Nodes,
+    ) -> Walker<'a> {
+        Walker {
+            graph: self,
+            instructions,
+            starts: self.select(|id| starts.contains(id)),
+            is_goal: (0..self.len() as NodeIndex)
+                .map(|index| goals.contains(self.id(index)))
+                .collect(),
+            max_steps: u64::MAX,
+        }
+    }
+}
+
+impl Walker<'_> {
+    /// Give up on walks that take more than `max_steps`.
+    pub fn with_max_steps(self, max_steps: u64) -> Self {
+        Walker { max_steps, ..self }
+    }
+
+    /// The fewest steps, at least one, after which every walk is on a goal. Works it out from
+    /// where each walk starts repeating rather than walking, so a large step cap costs nothing.
+    /// Picking one goal per walk can take a long time when the walks pass many goals, but the
+    /// cap cuts that short.
+    pub fn steps(&self) -> Outcome {
+        if self.starts.is_empty() {
+            return Outcome::NoStarts;
+        }
+        if !self.is_goal.contains(&true) {
+            return Outcome::NoGoals;
+        }
+        if self.instructions.is_empty() {
+            return Outcome::NoInstructions;
+        }
+        let cycles: Vec<Cycle> = self
+            .starts
+            .iter()
+            .map(|&start| {
+                Cycle::find(self.graph, self.instructions, start, |node| {
+                    self.is_goal[node as usize]
+                })
+            })
+            .collect();
+        match first_common_end(&cycles, self.max_steps) {
+            Some(steps) => Outcome::Reached(steps),
+            None if has_common_end(&cycles) => Outcome::PastMaxSteps,
+            None => Outcome::Never,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse;
+    use super::super::tests::EXAMPLE3;
+    use super::*;
+
+    fn graph(input: &str) -> (String, Graph) {
+        let (instructions, nodes) = parse(input).unwrap();
+        (instructions, Graph::new(&nodes))
+    }
+
+    #[test]
+    fn node_sets() {
+        assert!(Nodes::Id("22A".to_owned()).contains("22A"));
+        assert!(!Nodes::Id("22A".to_owned()).contains("2A"));
+        assert!(Nodes::Suffix("Z".to_owned()).contains("11Z"));
+        assert!(Nodes::Set(BTreeSet::from(["XXX".to_owned()])).contains("XXX"));
+
+        let pattern = Nodes::Pattern("1*.Z".to_owned());
+        assert!(pattern.contains("11Z"));
+        assert!(pattern.contains("1ABZ"));
+        assert!(!pattern.contains("1Z"));
+        assert!(!pattern.contains("11ZZA"));
+    }
+
+    #[test]
+    fn example_walks() {
+        let (instructions, graph) = graph(EXAMPLE3);
+        let suffix = |suffix: &str| Nodes::Suffix(suffix.to_owned());
+        let id = |id: &str| Nodes::Id(id.to_owned());
+
+        let walker = graph.walker(&instructions, &suffix("A"), &suffix("Z"));
+        assert_eq!(walker.steps(), Outcome::Reached(6));
+        assert_eq!(walker.with_max_steps(5).steps(), Outcome::PastMaxSteps);
+
+        let walker = graph.walker(&instructions, &id("22A"), &Nodes::Pattern("22*".to_owned()));
+        assert_eq!(walker.steps(), Outcome::Reached(1));
+
+        // Once on XXX a walk stays there.
+        let walker = graph.walker(&instructions, &id("11A"), &id("22Z"));
+        assert_eq!(walker.steps(), Outcome::Never);
+        assert_eq!(walker.with_max_steps(100).steps(), Outcome::Never);
+        assert_eq!(
+            graph
+                .walker(&instructions, &id("33A"), &suffix("Z"))
+                .steps(),
+            Outcome::NoStarts
+        );
+        assert_eq!(
+            graph.walker(&instructions, &id("11A"), &id("33Z")).steps(),
+            Outcome::NoGoals
+        );
+        assert_eq!(
+            graph.walker("", &id("11A"), &id("11Z")).steps(),
+            Outcome::NoInstructions
+        );
+    }
+
+    #[test]
+    fn broad_goals() {
+        // Each ghost goes round its own ring and every node in the back half of a ring is a goal,
+        // so there are thousands of ways to pick one goal per ghost.
+        let mut input = String::from("L\n\n");
+        let mut goals = BTreeSet::new();
+        for (ghost, length) in [7, 9, 11, 13, 16, 17].into_iter().enumerate() {
+            input += &format!("G{ghost}A = (G{ghost}N0, G{ghost}N0)\n");
+            for position in 0..length {
+                let next = (position + 1) % length;
+                input += &format!("G{ghost}N{position} = (G{ghost}N{next}, G{ghost}N{next})\n");
+                if position >= length / 2 {
+                    goals.insert(format!("G{ghost}N{position}"));
+                }
+            }
+        }
+        let (instructions, graph) = graph(&input);
+        let starts = Nodes::Suffix("A".to_owned());
+        let walker = graph.walker(&instructions, &starts, &Nodes::Set(goals.clone()));
+
+        let Outcome::Reached(steps) = walker.steps() else {
+            panic!("the ghosts line up");
+        };
+
+        // The same as stepping every walk forward until they're all on a goal.
+        let mut walks: Vec<_> = graph
+            .starts()
+            .iter()
+            .map(|&start| graph.walk(&instructions, start))
+            .collect();
+        let walked = (1..)
+            .find(|_| {
+                // Every walk has to take the step, so check them all afterwards.
+                let on_goals: Vec<bool> = walks
+                    .iter_mut()
+                    .map(|walk| goals.contains(graph.id(walk.next().unwrap())))
+                    .collect();
+                on_goals.iter().all(|&on_goal| on_goal)
+            })
+            .unwrap();
+        assert_eq!(steps, walked);
+
+        // Without any goals in the first ring its ghost can never finish.
+        goals.retain(|id| !id.starts_with("G0"));
+        let walker_without = graph.walker(&instructions, &starts, &Nodes::Set(goals));
+        assert_eq!(walker_without.with_max_steps(1000).steps(), Outcome::Never);
+        assert_eq!(
+            walker.clone().with_max_steps(steps).steps(),
+            Outcome::Reached(steps)
+        );
+        assert_eq!(
+            walker.with_max_steps(steps - 1).steps(),
+            Outcome::PastMaxSteps
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::error::Error;
+
+use crate::error::{Line, ParseError, ParseErrorKind};
+use crate::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(histories: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(histories
+            .iter()
+            .map(|history| extrapolate_next(history))
+            .sum())
+    }
+
+    fn part_two(histories: &Self::Input) -> Result<Self::Answer, Box<dyn Error>> {
+        Ok(histories
+            .iter()
+            .map(|history| extrapolate_previous(history))
+            .sum())
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Line::all(Day09::DAY, input)
+        .map(|line| match line.numbers(line.text)? {
+            history if history.is_empty() => {
+                Err(line.error(line.text, ParseErrorKind::Missing("number")))
+            }
+            history => Ok(history),
+        })
+        .collect()
+}
+
+/// Difference each level until the last row is all zeros, which it is when it's empty.
+fn difference_table(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut table: Vec<Vec<i64>> = Vec::new();
+    table.push(history.to_vec());
+    while table.last().unwrap().iter().any(|n| *n != 0) {
+        let differences = table
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|a| a[1] - a[0])
+            .collect::<Vec<i64>>();
+        table.push(differences);
+    }
+    table
+}
+
+/// The value that would come after the history. A single value is repeated.
+fn extrapolate_next(history: &[i64]) -> i64 {
+    difference_table(history)
+        .iter()
+        .rev()
+        .fold(0, |previous, row| previous + row.last().unwrap_or(&0))
+}
+
+/// The value that would come before the history. A single value is repeated.
+fn extrapolate_previous(history: &[i64]) -> i64 {
+    difference_table(history)
+        .iter()
+        .rev()
+        .fold(0, |previous, row| row.first().unwrap_or(&0) - previous)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+
+    #[test]
+    fn example_part_one() {
+        let histories = parse(EXAMPLE).unwrap();
+        let answer = Day09::part_one(&histories).unwrap();
+        assert_eq!(answer, 114);
+    }
+
+    #[test]
+    fn example_part_two() {
+        let histories = parse(EXAMPLE).unwrap();
+        let answer = Day09::part_two(&histories).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        let error = parse("0 3 6\n1 3 - 10").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.snippet, "-");
+    }
+
+    #[test]
+    fn parse_blank_line() {
+        let error = parse("0 3 6\n\n1 2 3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.kind, ParseErrorKind::Missing("number"));
+    }
+
+    #[test]
+    fn single_value() {
+        assert_eq!(extrapolate_next(&[7]), 7);
+        assert_eq!(extrapolate_previous(&[7]), 7);
+        assert_eq!(extrapolate_next(&[0]), 0);
+    }
+}
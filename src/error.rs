@@ -0,0 +1,176 @@
+//! Errors for malformed puzzle input.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Puzzle input that could not be parsed, with the location of the problem.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+
+    /// Starts at one.
+    pub line: usize,
+
+    /// Starts at one and counts characters, not bytes.
+    pub column: usize,
+
+    /// The text at fault, which may be empty if something was missing.
+    pub snippet: String,
+
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    /// Something required was not found, such as a separator or a whole line.
+    Missing(&'static str),
+
+    /// Text that should have been a number, or a number that is out of range.
+    InvalidNumber,
+
+    /// Something that is not allowed where it was found.
+    Unexpected(&'static str),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::Missing(what) => write!(f, "missing {what}"),
+            ParseErrorKind::InvalidNumber => f.write_str("invalid number"),
+            ParseErrorKind::Unexpected(what) => write!(f, "unexpected {what}"),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.kind
+        )?;
+        if !self.snippet.is_empty() {
+            write!(f, " '{}'", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of puzzle input that knows where it is, so errors can point at the problem.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub day: u8,
+
+    /// Starts at one.
+    pub number: usize,
+
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Number the lines of the input, starting at one.
+    pub fn all(day: u8, input: &'a str) -> impl Iterator<Item = Line<'a>> {
+        input.lines().enumerate().map(move |(index, text)| Line {
+            day,
+            number: index + 1,
+            text,
+        })
+    }
+
+    /// An error about `snippet`, which should be a slice of this line. Anything else is
+    /// reported at the end of the line.
+    pub fn error(&self, snippet: &str, kind: ParseErrorKind) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (snippet.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + snippet.len() <= self.text.len())
+            .unwrap_or(self.text.len());
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            snippet: snippet.to_owned(),
+            kind,
+        }
+    }
+
+    /// An error about something that should have come after `snippet`.
+    pub fn missing_after(&self, snippet: &str, what: &'static str) -> ParseError {
+        let end = &snippet[snippet.len()..];
+        self.error(end, ParseErrorKind::Missing(what))
+    }
+
+    /// Parse `snippet`, a slice of this line, as a number.
+    pub fn number<T: FromStr>(&self, snippet: &str) -> Result<T, ParseError> {
+        snippet
+            .parse()
+            .map_err(|_| self.error(snippet, ParseErrorKind::InvalidNumber))
+    }
+
+    /// Parse every whitespace separated word as a number.
+    pub fn numbers<T: FromStr>(&self, snippet: &str) -> Result<Vec<T>, ParseError> {
+        snippet
+            .split_whitespace()
+            .map(|word| self.number(word))
+            .collect()
+    }
+}
+
+/// An error about a line that doesn't exist because the input ended too soon.
+pub fn missing_line(day: u8, input: &str, what: &'static str) -> ParseError {
+    ParseError {
+        day,
+        line: input.lines().count() + 1,
+        column: 1,
+        snippet: String::new(),
+        kind: ParseErrorKind::Missing(what),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_snippet() {
+        let line = Line {
+            day: 4,
+            number: 2,
+            text: "Card 1: 41 x8 | 83",
+        };
+        let word = line.text.split_whitespace().nth(3).unwrap();
+        let error = line.number::<u32>(word).unwrap_err();
+        assert_eq!(error.column, 12);
+        assert_eq!(error.snippet, "x8");
+        assert_eq!(
+            error.to_string(),
+            "day 4, line 2, column 12: invalid number 'x8'"
+        );
+    }
+
+    #[test]
+    fn missing_after_snippet() {
+        let line = Line {
+            day: 6,
+            number: 1,
+            text: "Time  7",
+        };
+        let error = line.missing_after(line.text, "':'");
+        assert_eq!(error.column, 8);
+        assert_eq!(error.to_string(), "day 6, line 1, column 8: missing ':'");
+    }
+
+    #[test]
+    fn foreign_snippet_is_at_end_of_line() {
+        let line = Line {
+            day: 1,
+            number: 1,
+            text: "abc",
+        };
+        let error = line.error("other", ParseErrorKind::Unexpected("text"));
+        assert_eq!(error.column, 4);
+    }
+}
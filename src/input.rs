@@ -0,0 +1,58 @@
+//! Finding and reading puzzle input.
+//!
+//! Input is read from a path given on the command line, from stdin when the path is `-`, or
+//! otherwise from `dayNN.txt` in the data directory. The data directory is `AOC_DATA_DIR` if it
+//! is set and the `data` directory of this repository if not.
+
+use std::fs::read_to_string;
+use std::io::{read_to_string as read_all, stdin, Error as IoError};
+use std::path::PathBuf;
+
+/// Environment variable naming the directory holding the `dayNN.txt` inputs.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Path argument meaning the input should be read from stdin.
+pub const STDIN_PATH: &str = "-";
+
+pub fn data_dir() -> PathBuf {
+    std::env::var_os(DATA_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/data")))
+}
+
+/// Where the input for a day is read from when no path is given.
+pub fn default_path(day: u8) -> PathBuf {
+    data_dir().join(format!("day{day:02}.txt"))
+}
+
+/// Read the input for a day from `path`, or from the data directory if there is no path.
+pub fn read(day: u8, path: Option<&str>) -> Result<String, IoError> {
+    let path = match path {
+        Some(STDIN_PATH) => return read_all(stdin()),
+        Some(path) => PathBuf::from(path),
+        None => default_path(day),
+    };
+    read_to_string(&path)
+        .map_err(|error| IoError::new(error.kind(), format!("{}: {error}", path.display())))
+}
+
+/// Read the input for a day from the first command line argument, if any.
+pub fn read_from_args(day: u8) -> Result<String, IoError> {
+    read(day, std::env::args().nth(1).as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_path_name() {
+        assert!(default_path(5).ends_with("day05.txt"));
+    }
+
+    #[test]
+    fn missing_file_names_path() {
+        let error = read(1, Some("no/such/file.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("no/such/file.txt: "));
+    }
+}
@@ -0,0 +1,188 @@
+//! Solutions to the Advent of Code 2023.
+//!
+//! Each day is a module with a type implementing [`Solution`] so every puzzle can be driven
+//! the same way regardless of how its input is parsed.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+use std::str::FromStr;
+
+use bench::{bench, Benchmark};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use error::ParseError;
+
+pub mod answers;
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod error;
+pub mod input;
+
+/// A day of the Advent of Code. The input is parsed once and shared by both parts.
+pub trait Solution {
+    /// Day of December the puzzle was released.
+    const DAY: u8;
+
+    /// Puzzle input after parsing.
+    type Input;
+
+    /// Answer to either part of the puzzle.
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer, Box<dyn Error>>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer, Box<dyn Error>>;
+
+    /// Anything odd about the input that doesn't stop it being solved. Most days don't look.
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// One of the two puzzles released each day.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::A, Part::B];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => f.pad("a"),
+            Part::B => f.pad("b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = IoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "A" | "1" => Ok(Part::A),
+            "b" | "B" | "2" => Ok(Part::B),
+            _ => Err(IoError::other(format!("unknown part '{s}'"))),
+        }
+    }
+}
+
+/// Parses the input and solves one part, erasing the types so days can be chosen at runtime.
+pub type Solver = fn(&str, Part) -> Result<String, Box<dyn Error>>;
+
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<String, Box<dyn Error>> {
+    let input = S::parse(input)?;
+    let answer = match part {
+        Part::A => S::part_one(&input)?,
+        Part::B => S::part_two(&input)?,
+    };
+    Ok(answer.to_string())
+}
+
+/// Parses the input and lists its warnings, erasing the types like [`Solver`].
+pub type Checker = fn(&str) -> Result<Vec<String>, Box<dyn Error>>;
+
+pub fn check<S: Solution>(input: &str) -> Result<Vec<String>, Box<dyn Error>> {
+    Ok(S::warnings(&S::parse(input)?))
+}
+
+/// Times parsing and solving one part over a number of iterations, erasing the types like
+/// [`Solver`].
+pub type Bencher = fn(&str, Part, usize) -> Result<Benchmark, Box<dyn Error>>;
+
+/// A day that has been solved, with its types erased so it can be chosen at runtime.
+#[derive(Clone, Copy)]
+pub struct SolvedDay {
+    pub day: u8,
+    pub solve: Solver,
+    pub check: Checker,
+    pub bench: Bencher,
+}
+
+impl SolvedDay {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve::<S>,
+            check: check::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+/// Every day that has been solved, in order.
+pub const DAYS: [SolvedDay; 9] = [
+    SolvedDay::of::<Day01>(),
+    SolvedDay::of::<Day02>(),
+    SolvedDay::of::<Day03>(),
+    SolvedDay::of::<Day04>(),
+    SolvedDay::of::<Day05>(),
+    SolvedDay::of::<Day06>(),
+    SolvedDay::of::<Day07>(),
+    SolvedDay::of::<Day08>(),
+    SolvedDay::of::<Day09>(),
+];
+
+pub fn solved_day(day: u8) -> Option<SolvedDay> {
+    DAYS.iter().find(|solved| solved.day == day).copied()
+}
+
+pub fn solver(day: u8) -> Option<Solver> {
+    solved_day(day).map(|solved| solved.solve)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("a".parse::<Part>().unwrap(), Part::A);
+        assert_eq!("2".parse::<Part>().unwrap(), Part::B);
+        assert!("c".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn solve_by_day() {
+        let solve = solver(9).expect("day 9");
+        let answer = solve("0 3 6 9 12 15", Part::A).unwrap();
+        assert_eq!(answer, "18");
+        assert!(solver(25).is_none());
+    }
+
+    #[test]
+    fn check_by_day() {
+        let check = solved_day(5).expect("day 5").check;
+        let warnings = check("seeds: 1 2\n\nseed-to-location map:\n5 0 2\n5 1 2").unwrap();
+        assert_eq!(
+            warnings,
+            vec!["seed-to-location map: mappings 1 and 2 overlap at 1..2"]
+        );
+        assert_eq!(
+            (solved_day(9).expect("day 9").check)("1 2 3").unwrap(),
+            Vec::<String>::new()
+        );
+    }
+}